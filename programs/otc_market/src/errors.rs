@@ -17,4 +17,31 @@ pub enum OtcError {
     InvalidSrcBuyer,
     InvalidSrcSeller,
     NotCrosschainOffer,
+    Overflow,
+    ExcessiveFee,
+    MissingAccount,
+    InvalidReferrer,
+    ExcessiveReferrerShare,
+    Paused,
+    InvalidAmount,
+    InvalidExpiry,
+    OfferExpired,
+    OfferNotExpired,
+    InvalidOfferPayer,
+    OfferNotFound,
+    InvalidNonce,
+    InvalidLegacyOffer,
+    InvalidLegacyConfig,
+    CancelPending,
+    InvalidMinFill,
+    InvalidFillAmount,
+    OnlyAllowedBuyer,
+    InvalidAllowedBuyer,
+    InvalidBuyerProof,
+    ExcessiveBuyerFill,
+    OfferNotClosed,
+    ExcessiveExchangeRate,
+    SlippageExceeded,
+    UnsupportedTokenExtension,
+    InvalidEscrowVault,
 }
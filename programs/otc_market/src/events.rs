@@ -11,6 +11,12 @@ pub struct OfferCreated {
     pub dst_token_address: [u8; 32],
     pub src_amount_sd: u64,
     pub exchange_rate_sd: u64,
+    pub expires_at: Option<i64>,
+    pub nonce: u64,
+    pub min_fill_sd: u64,
+    pub all_or_nothing: bool,
+    pub allowed_buyer: Option<[u8; 32]>,
+    pub buyers_root: Option<[u8; 32]>,
 }
 
 #[event]
@@ -19,9 +25,114 @@ pub struct OfferAccepted {
     pub src_amount_sd: u64,
     pub src_buyer_address: [u8; 32],
     pub dst_buyer_address: [u8; 32],
+    pub referrer: Option<Pubkey>,
 }
 
 #[event]
 pub struct OfferCanceled {
     pub offer_id: [u8; 32],
 }
+
+#[event]
+pub struct OfferFilled {
+    pub offer_id: [u8; 32],
+}
+
+#[event]
+pub struct OfferToppedUp {
+    pub offer_id: [u8; 32],
+    pub src_amount_sd: u64,
+}
+
+#[event]
+pub struct OfferReduced {
+    pub offer_id: [u8; 32],
+    pub src_amount_sd: u64,
+}
+
+#[event]
+pub struct OfferRepriced {
+    pub offer_id: [u8; 32],
+    pub exchange_rate_sd: u64,
+}
+
+#[event]
+pub struct AdminProposed {
+    pub admin: Pubkey,
+    pub pending_admin: Option<Pubkey>,
+}
+
+#[event]
+pub struct AdminChanged {
+    pub old_admin: Pubkey,
+    pub new_admin: Pubkey,
+}
+
+#[event]
+pub struct RoleChanged {
+    pub role: Role,
+    pub old_account: Pubkey,
+    pub new_account: Pubkey,
+}
+
+#[event]
+pub struct TreasuryChanged {
+    pub old_treasury: Pubkey,
+    pub new_treasury: Pubkey,
+}
+
+#[event]
+pub struct FeesWithdrawn {
+    pub token_address: [u8; 32],
+    pub destination: Pubkey,
+    pub amount_ld: u64,
+}
+
+#[event]
+pub struct FeeChanged {
+    pub maker_fee_bps: u16,
+    pub taker_fee_bps: u16,
+}
+
+#[event]
+pub struct FeeOverrideChanged {
+    pub dst_token_address: Option<[u8; 32]>,
+    pub eid: Option<u32>,
+    pub fee_bps: Option<u16>,
+}
+
+#[event]
+pub struct ReferrerChanged {
+    pub referrer: Pubkey,
+    pub max_share_bps: u16,
+}
+
+#[event]
+pub struct PauseChanged {
+    pub eid: Option<u32>,
+    pub paused: bool,
+    pub pause_cancellations: bool,
+}
+
+#[event]
+pub struct ConfigMigrated {
+    pub eid: u32,
+}
+
+#[event]
+pub struct OfferMigrated {
+    pub offer_id: [u8; 32],
+}
+
+#[event]
+pub struct TokenExtensionPolicyChanged {
+    pub token_extension_policy: u8,
+}
+
+#[event]
+pub struct EscrowSurplusSwept {
+    pub offer_id: [u8; 32],
+    pub token_address: [u8; 32],
+    pub destination: Pubkey,
+    pub amount_ld: u64,
+}
@@ -0,0 +1,43 @@
+use crate::*;
+use oapp::endpoint::instructions::SetDelegateParams;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct AcceptAdmin<'info> {
+    pub pending_admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [OtcConfig::OTC_SEED],
+        bump = otc_config.bump,
+        constraint = otc_config.pending_admin == Some(pending_admin.key()) @ OtcError::Unauthorized
+    )]
+    pub otc_config: Account<'info, OtcConfig>,
+}
+
+impl AcceptAdmin<'_> {
+    /// NOTICE: remaining accounts are the endpoint set_delegate accounts, the new admin becomes the oapp delegate
+    pub fn apply(ctx: &mut Context<AcceptAdmin>) -> Result<()> {
+        let old_admin = ctx.accounts.otc_config.admin;
+
+        ctx.accounts.otc_config.admin = ctx.accounts.pending_admin.key();
+        ctx.accounts.otc_config.pending_admin = None;
+
+        oapp::endpoint_cpi::set_delegate(
+            ctx.accounts.otc_config.endpoint_program,
+            ctx.accounts.otc_config.key(),
+            ctx.remaining_accounts,
+            &[OtcConfig::OTC_SEED, &[ctx.accounts.otc_config.bump]],
+            SetDelegateParams {
+                delegate: ctx.accounts.otc_config.admin,
+            }
+        )?;
+
+        emit_cpi!(AdminChanged {
+            old_admin,
+            new_admin: ctx.accounts.otc_config.admin,
+        });
+
+        Ok(())
+    }
+}
@@ -23,11 +23,64 @@ pub struct AcceptOffer<'info> {
         mut, 
         seeds = [&params.offer_id], 
         bump = offer.bump,
-        constraint = offer.dst_eid == OtcConfig::EID @ OtcError::InvalidEid,
+        constraint = offer.dst_eid == otc_config.eid @ OtcError::InvalidEid,
         constraint = offer.src_amount_sd >= params.src_amount_sd @ OtcError::ExcessiveAmount
     )]
     pub offer: Box<Account<'info, Offer>>,
 
+    #[account(
+        mut,
+        constraint = offer_payer.key() == offer.payer @ OtcError::InvalidOfferPayer
+    )]
+    /// CHECK: asserted against the one stored in the offer
+    /// NOTICE: required for the accept that fully fills the offer - rent: to
+    pub offer_payer: Option<AccountInfo<'info>>,
+
+    #[account(seeds = [EidPause::PAUSE_SEED, otc_config.key().as_ref(), &offer.src_eid.to_be_bytes()], bump)]
+    /// CHECK: eid pause switch, may be uninitialized
+    pub eid_pause: AccountInfo<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = buyer,
+        space = 8 + FeeLedger::INIT_SPACE,
+        seeds = [FeeLedger::FEE_LEDGER_SEED, offer.dst_token_address.as_ref()],
+        bump
+    )]
+    pub fee_ledger: Box<Account<'info, FeeLedger>>,
+
+    #[account(
+        seeds = [
+            FeeOverride::TOKEN_FEE_SEED.as_ref(),
+            otc_config.key().as_ref(),
+            offer.dst_token_address.as_ref(),
+        ],
+        bump
+    )]
+    /// CHECK: dst token fee override, may be uninitialized
+    pub token_fee: AccountInfo<'info>,
+
+    #[account(
+        seeds = [
+            FeeOverride::EID_FEE_SEED.as_ref(),
+            otc_config.key().as_ref(),
+            &offer.src_eid.to_be_bytes(),
+        ],
+        bump
+    )]
+    /// CHECK: src eid fee override, may be uninitialized - the dst eid of an acceptable offer is always the local eid
+    pub eid_fee: AccountInfo<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = buyer,
+        space = 8 + BuyerFill::INIT_SPACE,
+        seeds = [BuyerFill::BUYER_FILL_SEED.as_ref(), &params.offer_id, buyer.key().as_ref()],
+        bump
+    )]
+    /// NOTICE: required for allowlisted offer
+    pub buyer_fill: Option<Box<Account<'info, BuyerFill>>>,
+
     // dst
 
     #[account(
@@ -67,17 +120,39 @@ pub struct AcceptOffer<'info> {
     /// NOTICE: required for dst spl token - fee: to_ata
     pub dst_treasury_ata: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
 
+    #[account(mut, seeds = [Treasury::TREASURY_SEED], bump = treasury.bump)]
+    /// NOTICE: dst sol token - fee: to | dst spl token - fee: authority of to_ata
+    pub treasury: Box<Account<'info, Treasury>>,
+
     #[account(
-        mut, 
-        // constraint = treasury.key() == otc_config.treasury @ OtcError::InvalidTreasury
+        seeds = [
+            Referrer::REFERRER_SEED.as_ref(),
+            params.referrer.unwrap_or_default().as_ref(),
+        ],
+        bump = referrer_config.bump
+    )]
+    /// NOTICE: required for referred accept
+    pub referrer_config: Option<Box<Account<'info, Referrer>>>,
+
+    #[account(mut)]
+    /// CHECK: asserted against the one in params
+    /// NOTICE: required for referred accept - dst sol token: to | dst spl token: (init_if_needed)
+    pub referrer: Option<AccountInfo<'info>>,
+
+    #[account(
+        init_if_needed,
+        payer = buyer,
+        associated_token::authority = referrer,
+        associated_token::mint = dst_token_mint,
+        associated_token::token_program = token_program
     )]
-    /// NOTICE: required for dst sol token - fee: to
-    pub treasury: Option<AccountInfo<'info>>,
+    /// NOTICE: required for referred accept - dst spl token: to_ata
+    pub dst_referrer_ata: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
 
     #[account(
         mint::token_program = token_program,
         // constraint = dst_token_mint.key() == Pubkey::new_from_array(offer.dst_token_address) @ OtcError::InvalidDstTokenMint,
-        constraint = dst_token_mint.decimals >= OtcConfig::SHARED_DECIMALS @ OtcError::InvalidLocalDecimals
+        constraint = dst_token_mint.decimals <= OtcConfig::MAX_LOCAL_DECIMALS @ OtcError::InvalidLocalDecimals
     )]
     /// NOTICE: required for dst spl token - token_mint
     pub dst_token_mint: Option<Box<InterfaceAccount<'info, Mint>>>,
@@ -89,26 +164,46 @@ pub struct AcceptOffer<'info> {
         payer = buyer,
         associated_token::authority = buyer,
         associated_token::mint = src_token_mint,
-        associated_token::token_program = token_program
+        associated_token::token_program = src_token_program
     )]
     /// NOTICE: required for src spl token - to_ata
     pub src_buyer_ata: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
 
+    #[account(
+        init_if_needed,
+        payer = buyer,
+        associated_token::authority = offer_payer,
+        associated_token::mint = src_token_mint,
+        associated_token::token_program = src_token_program
+    )]
+    /// NOTICE: required for the accept that fully fills a src spl token offer - rounding dust: to_ata,
+    /// the offer payer of a monochain offer is its src seller
+    pub src_seller_ata: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
     #[account(
         mut,
         associated_token::authority = escrow,
         associated_token::mint = src_token_mint,
-        associated_token::token_program = token_program
+        associated_token::token_program = src_token_program
     )]
     /// NOTICE: required for src spl token - from_ata
     pub src_escrow_ata: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
 
-    #[account(mut, seeds = [Escrow::ESCROW_SEED], bump = escrow.bump)]
+    #[account(mut, seeds = [Escrow::ESCROW_SEED, &params.offer_id], bump = escrow.bump)]
     /// NOTICE: required for src sol token - from | required for src spl token - authority
     pub escrow: Option<Box<Account<'info, Escrow>>>,
 
     #[account(
-        mint::token_program = token_program
+        mut,
+        seeds = [EscrowLedger::ESCROW_LEDGER_SEED, offer.src_token_address.as_ref()],
+        bump = escrow_ledger.bump
+    )]
+    /// NOTICE: required for monochain offer
+    pub escrow_ledger: Option<Box<Account<'info, EscrowLedger>>>,
+
+    #[account(
+        mut,
+        mint::token_program = src_token_program
         // constraint = src_token_mint.key() == Pubkey::new_from_array(offer.src_token_address) @ OtcError::InvalidSrcTokenMint
     )]
     /// NOTICE: required for src spl token - token_mint
@@ -134,8 +229,12 @@ pub struct AcceptOffer<'info> {
 
     pub associated_token_program: Option<Program<'info, AssociatedToken>>,
 
+    /// NOTICE: required for dst spl token
     pub token_program: Option<Interface<'info, TokenInterface>>,
 
+    /// NOTICE: required for monochain offer - src spl token, may differ from the dst one
+    pub src_token_program: Option<Interface<'info, TokenInterface>>,
+
     pub system_program: Program<'info, System>,
 }
 
@@ -145,6 +244,55 @@ impl AcceptOffer<'_> {
         params: &AcceptOfferParams,
         fee: &MessagingFee
     ) -> Result<(AcceptOfferReceipt, MessagingReceipt)> {
+        ctx.accounts.otc_config.assert_not_paused(&ctx.accounts.eid_pause)?;
+        ctx.accounts.otc_config.assert_token_extensions_allowed(
+            ctx.accounts.dst_token_mint.as_deref()
+        )?;
+
+        require!(
+            !ctx.accounts.offer.is_expired(Clock::get()?.unix_timestamp),
+            OtcError::OfferExpired
+        );
+
+        require!(
+            ctx.accounts.offer.is_fill_allowed(params.src_amount_sd),
+            OtcError::InvalidFillAmount
+        );
+
+        // the offer may have been repriced since the buyer quoted it
+        require!(
+            ctx.accounts.offer.exchange_rate_sd <= params.max_exchange_rate_sd,
+            OtcError::ExcessiveExchangeRate
+        );
+
+        require!(
+            ctx.accounts.offer.is_buyer_allowed(&ctx.accounts.buyer.key().to_bytes()),
+            OtcError::OnlyAllowedBuyer
+        );
+
+        if ctx.accounts.offer.buyers_root.is_some() {
+            // allowlisted offer
+            require!(
+                ctx.accounts.offer.is_buyer_listed(
+                    &ctx.accounts.buyer.key().to_bytes(),
+                    params.buyer_max_fill_sd,
+                    &params.buyer_proof
+                ),
+                OtcError::InvalidBuyerProof
+            );
+
+            let buyer_fill = ctx.accounts.buyer_fill.as_mut().expect(OtcConfig::ERROR_MSG);
+            buyer_fill.filled_sd = buyer_fill.filled_sd
+                .checked_add(params.src_amount_sd)
+                .ok_or(OtcError::Overflow)?;
+            buyer_fill.bump = ctx.bumps.buyer_fill;
+
+            require!(
+                params.buyer_max_fill_sd == 0 || buyer_fill.filled_sd <= params.buyer_max_fill_sd,
+                OtcError::ExcessiveBuyerFill
+            );
+        }
+
         {
             // assert accounts match offer params
             if let Some(src_token_mint) = ctx.accounts.src_token_mint.as_ref() {
@@ -163,29 +311,62 @@ impl AcceptOffer<'_> {
                 );
             }
 
-            if let Some(treasury) = ctx.accounts.treasury.as_ref() {
-                require!(
-                    treasury.key() == ctx.accounts.otc_config.treasury,
-                    OtcError::InvalidTreasury
-                );
-            }
-
             require!(
                 ctx.accounts.dst_seller.key() ==
                     Pubkey::new_from_array(ctx.accounts.offer.dst_seller_address),
                 OtcError::InvalidDstSeller
             );
+
+            if let Some(referrer) = params.referrer {
+                let referrer_config = ctx.accounts.referrer_config
+                    .as_ref()
+                    .ok_or(OtcError::InvalidReferrer)?;
+                require!(
+                    ctx.accounts.referrer.as_ref().map(|r| r.key()) == Some(referrer),
+                    OtcError::InvalidReferrer
+                );
+                require!(
+                    ctx.accounts.dst_token_mint.is_none() || ctx.accounts.dst_referrer_ata.is_some(),
+                    OtcError::MissingAccount
+                );
+                require!(
+                    params.referrer_share_bps <= referrer_config.max_share_bps,
+                    OtcError::ExcessiveReferrerShare
+                );
+            }
         }
 
+        let taker_fee_bps = ctx.accounts.otc_config.get_taker_fee_bps(
+            &ctx.accounts.token_fee,
+            &ctx.accounts.eid_fee
+        )?;
+
         let dst_token_mint = ctx.accounts.dst_token_mint.as_deref();
         let accept_offer_receipt = OtcConfig::to_dst_amount(
             params.src_amount_sd,
             ctx.accounts.offer.exchange_rate_sd,
-            dst_token_mint
+            dst_token_mint,
+            taker_fee_bps,
+            params.get_referrer_share_bps()
+        )?;
+
+        require!(
+            accept_offer_receipt.dst_amount_ld <= params.max_dst_amount_ld,
+            OtcError::SlippageExceeded
         );
 
+        // the treasury receives its share net of the token-2022 transfer fee
+        let treasury_fee_ld =
+            accept_offer_receipt.taker_fee_ld - accept_offer_receipt.referrer_fee_ld;
+        let treasury_transfer_fee_ld = OtcConfig::get_transfer_fee(dst_token_mint, treasury_fee_ld)?;
+
         // update state
         ctx.accounts.offer.src_amount_sd -= params.src_amount_sd;
+        ctx.accounts.fee_ledger.collect(
+            &ctx.accounts.offer.dst_token_address,
+            ctx.bumps.fee_ledger,
+            treasury_fee_ld - treasury_transfer_fee_ld
+        );
 
         // emit event
         emit_cpi!(OfferAccepted {
@@ -193,6 +374,7 @@ impl AcceptOffer<'_> {
             src_amount_sd: params.src_amount_sd,
             src_buyer_address: params.src_buyer_address,
             dst_buyer_address: ctx.accounts.buyer.key().to_bytes(),
+            referrer: params.referrer,
         });
 
         // send dst tokens
@@ -202,7 +384,7 @@ impl AcceptOffer<'_> {
             // (amount - fee) to seller
             OtcConfig::transfer(
                 ctx.accounts.buyer.as_ref(),
-                accept_offer_receipt.dst_amount_ld - accept_offer_receipt.fee_ld,
+                accept_offer_receipt.dst_amount_ld - accept_offer_receipt.taker_fee_ld,
                 Some(ctx.accounts.dst_seller.as_ref()),
                 ctx.accounts.token_program.as_ref(),
                 dst_buyer_ata,
@@ -211,17 +393,31 @@ impl AcceptOffer<'_> {
                 None
             )?;
 
-            // fee to treasury
+            // (fee - referrer fee) to treasury
             OtcConfig::transfer(
                 ctx.accounts.buyer.as_ref(),
-                accept_offer_receipt.fee_ld,
-                ctx.accounts.treasury.as_ref(),
+                accept_offer_receipt.taker_fee_ld - accept_offer_receipt.referrer_fee_ld,
+                Some(&ctx.accounts.treasury.to_account_info()),
                 ctx.accounts.token_program.as_ref(),
                 dst_buyer_ata,
                 dst_token_mint,
                 ctx.accounts.dst_treasury_ata.as_deref(),
                 None
             )?;
+
+            // referrer fee to referrer
+            if params.referrer.is_some() {
+                OtcConfig::transfer(
+                    ctx.accounts.buyer.as_ref(),
+                    accept_offer_receipt.referrer_fee_ld,
+                    ctx.accounts.referrer.as_ref(),
+                    ctx.accounts.token_program.as_ref(),
+                    dst_buyer_ata,
+                    dst_token_mint,
+                    ctx.accounts.dst_referrer_ata.as_deref(),
+                    None
+                )?;
+            }
         }
 
         let mut receipt = MessagingReceipt::default();
@@ -232,21 +428,23 @@ impl AcceptOffer<'_> {
 
             let amount_ld: u64;
             {
-                let decimal_conversion_rate =
-                    OtcConfig::get_decimal_conversion_rate(src_token_mint);
-                amount_ld = OtcConfig::sd2ld(params.src_amount_sd, decimal_conversion_rate);
+                let local_decimals = OtcConfig::get_local_decimals(src_token_mint);
+                amount_ld = OtcConfig::sd2ld(params.src_amount_sd, local_decimals);
             }
 
+            // update state
+            ctx.accounts.escrow_ledger.as_mut().expect(OtcConfig::ERROR_MSG).release(amount_ld)?;
+
             // send src tokens to the buyer
             OtcConfig::transfer(
                 escrow.to_account_info().as_ref(),
                 amount_ld,
                 Some(ctx.accounts.buyer.as_ref()),
-                ctx.accounts.token_program.as_ref(),
+                ctx.accounts.src_token_program.as_ref(),
                 ctx.accounts.src_escrow_ata.as_deref(),
                 src_token_mint,
                 ctx.accounts.src_buyer_ata.as_deref(),
-                Some(&[&[Escrow::ESCROW_SEED, &[escrow.bump]]])
+                Some(&[&[Escrow::ESCROW_SEED, &params.offer_id, &[escrow.bump]]])
             )?;
         } else {
             let peer = ctx.accounts.peer.as_ref().expect(OtcConfig::ERROR_MSG);
@@ -258,7 +456,10 @@ impl AcceptOffer<'_> {
                 &params.offer_id,
                 params.src_amount_sd,
                 &params.src_buyer_address,
-                &ctx.accounts.buyer.key().to_bytes()
+                &ctx.accounts.buyer.key().to_bytes(),
+                &ctx.accounts.offer.src_token_address,
+                &ctx.accounts.offer.src_seller_address,
+                &ctx.accounts.offer.src_token_program
             );
 
             receipt = oapp::endpoint_cpi::send(
@@ -277,6 +478,33 @@ impl AcceptOffer<'_> {
             )?;
         }
 
+        // close fully filled offer
+        if ctx.accounts.offer.src_amount_sd == 0 {
+            // emit event
+            emit_cpi!(OfferFilled {
+                offer_id: params.offer_id,
+            });
+
+            let offer_payer = ctx.accounts.offer_payer.as_ref().expect(OtcConfig::ERROR_MSG);
+
+            if ctx.accounts.offer.src_eid == ctx.accounts.offer.dst_eid {
+                // monochain offer - delete its escrow vault, rounding dust goes back to the seller
+                Escrow::close_vault(
+                    ctx.accounts.escrow.as_deref().expect(OtcConfig::ERROR_MSG),
+                    &params.offer_id,
+                    ctx.accounts.escrow_ledger.as_mut().expect(OtcConfig::ERROR_MSG),
+                    offer_payer,
+                    ctx.accounts.src_token_program.as_ref(),
+                    ctx.accounts.src_escrow_ata.as_deref(),
+                    ctx.accounts.src_token_mint.as_deref(),
+                    ctx.accounts.src_seller_ata.as_deref()
+                )?;
+            }
+
+            // delete offer
+            close(ctx.accounts.offer.to_account_info(), offer_payer.to_account_info())?;
+        }
+
         Ok((accept_offer_receipt, receipt))
     }
 }
@@ -286,10 +514,32 @@ pub struct AcceptOfferParams {
     pub offer_id: [u8; 32],
     pub src_amount_sd: u64,
     pub src_buyer_address: [u8; 32],
+    pub referrer: Option<Pubkey>,
+    pub referrer_share_bps: u16,
+    /// NOTICE: highest exchange rate the buyer agrees to, usually the quoted one
+    pub max_exchange_rate_sd: u64,
+    /// NOTICE: highest dst amount (fees included) the buyer agrees to pay, usually the quoted one (u64::MAX to quote)
+    pub max_dst_amount_ld: u64,
+    /// NOTICE: required for allowlisted offer - the leaf of the buyer, see [Offer::is_buyer_listed]
+    pub buyer_max_fill_sd: u64,
+    pub buyer_proof: Vec<[u8; 32]>,
+}
+
+impl AcceptOfferParams {
+    pub fn get_referrer_share_bps(&self) -> u16 {
+        if self.referrer.is_some() { self.referrer_share_bps } else { 0 }
+    }
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct AcceptOfferReceipt {
+    /// NOTICE: total taken from the buyer, fees included
     pub dst_amount_ld: u64,
-    pub fee_ld: u64,
+    pub taker_fee_ld: u64,
+    pub taker_fee_bps: u16,
+    pub referrer_fee_ld: u64,
+    /// NOTICE: received by the seller, net of the taker fee and the token-2022 transfer fee
+    pub dst_seller_amount_ld: u64,
+    /// NOTICE: token-2022 transfer fee withheld across all dst transfers
+    pub transfer_fee_ld: u64,
 }
@@ -20,11 +20,15 @@ pub struct CancelOffer<'info> {
         mut,
         seeds = [&offer_id], 
         bump = offer.bump,
-        constraint = offer.src_eid == OtcConfig::EID @ OtcError::InvalidEid,
+        constraint = offer.src_eid == otc_config.eid @ OtcError::InvalidEid,
         constraint = offer.src_seller_address == seller.key().to_bytes() @ OtcError::OnlySeller,
     )]
     pub offer: Account<'info, Offer>,
 
+    #[account(seeds = [EidPause::PAUSE_SEED, otc_config.key().as_ref(), &offer.dst_eid.to_be_bytes()], bump)]
+    /// CHECK: eid pause switch, may be uninitialized
+    pub eid_pause: AccountInfo<'info>,
+
     /// src - NOTICE: required for monochain offer
 
     #[account(
@@ -45,11 +49,20 @@ pub struct CancelOffer<'info> {
     /// NOTICE: required for src spl token - from_ata
     pub src_escrow_ata: Option<InterfaceAccount<'info, TokenAccount>>,
 
-    #[account(mut, seeds = [Escrow::ESCROW_SEED], bump = escrow.bump)]
+    #[account(mut, seeds = [Escrow::ESCROW_SEED, &offer_id], bump = escrow.bump)]
     /// NOTICE: required for src sol token - from | required for src spl token - authority
     pub escrow: Option<Account<'info, Escrow>>,
 
     #[account(
+        mut,
+        seeds = [EscrowLedger::ESCROW_LEDGER_SEED, offer.src_token_address.as_ref()],
+        bump = escrow_ledger.bump
+    )]
+    /// NOTICE: required for monochain offer
+    pub escrow_ledger: Option<Account<'info, EscrowLedger>>,
+
+    #[account(
+        mut,
         mint::token_program = token_program,
         constraint = src_token_mint.key() == Pubkey::new_from_array(offer.src_token_address) @ OtcError::InvalidSrcTokenMint,
     )]
@@ -84,6 +97,8 @@ impl CancelOffer<'_> {
         fee: &MessagingFee,
         extra_options: &Vec<u8>
     ) -> Result<MessagingReceipt> {
+        ctx.accounts.otc_config.assert_cancellations_not_paused(&ctx.accounts.eid_pause)?;
+
         let mut receipt = MessagingReceipt::default();
 
         if ctx.accounts.offer.src_eid == ctx.accounts.offer.dst_eid {
@@ -93,14 +108,13 @@ impl CancelOffer<'_> {
 
             let amount_ld: u64;
             {
-                let decimal_conversion_rate =
-                    OtcConfig::get_decimal_conversion_rate(src_token_mint);
-                amount_ld = OtcConfig::sd2ld(
-                    ctx.accounts.offer.src_amount_sd,
-                    decimal_conversion_rate
-                );
+                let local_decimals = OtcConfig::get_local_decimals(src_token_mint);
+                amount_ld = OtcConfig::sd2ld(ctx.accounts.offer.src_amount_sd, local_decimals);
             }
 
+            // update state
+            ctx.accounts.escrow_ledger.as_mut().expect(OtcConfig::ERROR_MSG).release(amount_ld)?;
+
             // send src tokens to the seller
             OtcConfig::transfer(
                 escrow.as_ref(),
@@ -110,7 +124,7 @@ impl CancelOffer<'_> {
                 ctx.accounts.src_escrow_ata.as_ref(),
                 src_token_mint,
                 ctx.accounts.src_seller_ata.as_ref(),
-                Some(&[&[Escrow::ESCROW_SEED, &[escrow.bump]]])
+                Some(&[&[Escrow::ESCROW_SEED, offer_id, &[escrow.bump]]])
             )?;
 
             // emit event
@@ -118,7 +132,17 @@ impl CancelOffer<'_> {
                 offer_id: *offer_id,
             });
 
-            // delete offer
+            // delete offer and its escrow vault
+            Escrow::close_vault(
+                escrow,
+                offer_id,
+                ctx.accounts.escrow_ledger.as_mut().expect(OtcConfig::ERROR_MSG),
+                ctx.accounts.seller.as_ref(),
+                ctx.accounts.token_program.as_ref(),
+                ctx.accounts.src_escrow_ata.as_ref(),
+                src_token_mint,
+                ctx.accounts.src_seller_ata.as_ref()
+            )?;
             close(ctx.accounts.offer.to_account_info(), ctx.accounts.seller.to_account_info())?;
         } else {
             // crosschain offer
@@ -127,6 +151,9 @@ impl CancelOffer<'_> {
                 .as_ref()
                 .expect(OtcConfig::ERROR_MSG);
 
+            // update state
+            ctx.accounts.offer.cancel_pending = true;
+
             let payload = build_cancel_offer_order_payload(&offer_id);
 
             receipt = oapp::endpoint_cpi::send(
@@ -0,0 +1,28 @@
+use crate::*;
+
+#[derive(Accounts)]
+#[instruction(offer_id: [u8; 32])]
+pub struct CloseBuyerFill<'info> {
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+
+    #[account(seeds = [&offer_id], bump)]
+    /// CHECK: the offer the fill was tracked for, has to be closed
+    pub offer: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [BuyerFill::BUYER_FILL_SEED.as_ref(), &offer_id, buyer.key().as_ref()],
+        bump = buyer_fill.bump
+    )]
+    pub buyer_fill: Account<'info, BuyerFill>,
+}
+
+impl CloseBuyerFill<'_> {
+    /// NOTICE: returns the rent of a buyer fill to the buyer once its offer is closed
+    pub fn apply(ctx: &mut Context<CloseBuyerFill>) -> Result<()> {
+        require!(ctx.accounts.offer.data_is_empty(), OtcError::OfferNotClosed);
+
+        close(ctx.accounts.buyer_fill.to_account_info(), ctx.accounts.buyer.to_account_info())
+    }
+}
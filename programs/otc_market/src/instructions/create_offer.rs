@@ -1,6 +1,5 @@
 use crate::*;
 use anchor_spl::{
-    token::ID as TOKEN_PROGRAM_ID,
     associated_token::AssociatedToken,
     token_interface::{ Mint, TokenAccount, TokenInterface },
 };
@@ -23,11 +22,12 @@ pub struct CreateOffer<'info> {
         seeds = [
             &Offer::hash_offer(
                 &seller.key().to_bytes(),
-                OtcConfig::EID,
+                otc_config.eid,
                 params.dst_eid,
                 &OtcConfig::get_token_address(src_token_mint.as_ref()),
                 &params.dst_token_address,
-                params.exchange_rate_sd
+                params.exchange_rate_sd,
+                params.nonce
             ),
         ],
         space = 8 + Offer::INIT_SPACE,
@@ -35,15 +35,71 @@ pub struct CreateOffer<'info> {
     )]
     pub offer: Account<'info, Offer>,
 
+    #[account(
+        init_if_needed,
+        payer = seller,
+        space = 8 + SellerNonce::INIT_SPACE,
+        seeds = [SellerNonce::SELLER_NONCE_SEED.as_ref(), seller.key().as_ref()],
+        bump
+    )]
+    pub seller_nonce: Box<Account<'info, SellerNonce>>,
+
+    #[account(
+        init_if_needed,
+        payer = seller,
+        space = 8 + FeeLedger::INIT_SPACE,
+        seeds = [
+            FeeLedger::FEE_LEDGER_SEED,
+            OtcConfig::get_token_address(src_token_mint.as_ref()).as_ref(),
+        ],
+        bump
+    )]
+    /// NOTICE: required for non-zero maker fee
+    pub fee_ledger: Option<Box<Account<'info, FeeLedger>>>,
+
+    #[account(
+        init_if_needed,
+        payer = seller,
+        space = 8 + EscrowLedger::INIT_SPACE,
+        seeds = [
+            EscrowLedger::ESCROW_LEDGER_SEED,
+            OtcConfig::get_token_address(src_token_mint.as_ref()).as_ref(),
+        ],
+        bump
+    )]
+    pub escrow_ledger: Box<Account<'info, EscrowLedger>>,
+
     #[account(seeds = [OtcConfig::OTC_SEED], bump = otc_config.bump)]
     pub otc_config: Account<'info, OtcConfig>,
 
-    #[account(mut, seeds = [Escrow::ESCROW_SEED], bump = escrow.bump)]
-    pub escrow: Account<'info, Escrow>,
+    #[account(seeds = [EidPause::PAUSE_SEED, otc_config.key().as_ref(), &params.dst_eid.to_be_bytes()], bump)]
+    /// CHECK: eid pause switch, may be uninitialized
+    pub eid_pause: AccountInfo<'info>,
+
+    #[account(
+        init,
+        payer = seller,
+        space = 8 + Escrow::INIT_SPACE,
+        seeds = [
+            Escrow::ESCROW_SEED.as_ref(),
+            &Offer::hash_offer(
+                &seller.key().to_bytes(),
+                otc_config.eid,
+                params.dst_eid,
+                &OtcConfig::get_token_address(src_token_mint.as_ref()),
+                &params.dst_token_address,
+                params.exchange_rate_sd,
+                params.nonce
+            ),
+        ],
+        bump
+    )]
+    /// NOTICE: src sol offer - escrowed amount: to | src spl offer - escrowed amount: authority of to_ata
+    pub escrow: Box<Account<'info, Escrow>>,
 
     #[account(
         mint::token_program = token_program,
-        constraint = src_token_mint.decimals >= OtcConfig::SHARED_DECIMALS @ OtcError::InvalidLocalDecimals
+        constraint = src_token_mint.decimals <= OtcConfig::MAX_LOCAL_DECIMALS @ OtcError::InvalidLocalDecimals
     )]
     /// NOTICE: required for src spl offer
     pub src_token_mint: Option<InterfaceAccount<'info, Mint>>,
@@ -58,7 +114,7 @@ pub struct CreateOffer<'info> {
     pub src_seller_ata: Option<InterfaceAccount<'info, TokenAccount>>,
 
     #[account(
-        init_if_needed,
+        init,
         payer = seller,
         associated_token::authority = escrow,
         associated_token::mint = src_token_mint,
@@ -67,6 +123,20 @@ pub struct CreateOffer<'info> {
     /// NOTICE: required for src spl offer
     pub src_escrow_ata: Option<InterfaceAccount<'info, TokenAccount>>,
 
+    #[account(mut, seeds = [Treasury::TREASURY_SEED], bump = treasury.bump)]
+    /// NOTICE: src sol offer - maker fee: to | src spl offer - maker fee: authority of to_ata
+    pub treasury: Box<Account<'info, Treasury>>,
+
+    #[account(
+        init_if_needed,
+        payer = seller,
+        associated_token::authority = treasury,
+        associated_token::mint = src_token_mint,
+        associated_token::token_program = token_program
+    )]
+    /// NOTICE: required for src spl offer with non-zero maker fee - maker fee: to_ata
+    pub src_treasury_ata: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
     #[account(
         seeds = [Peer::PEER_SEED, otc_config.key().as_ref(), &params.dst_eid.to_be_bytes()],
         bump = peer.bump
@@ -85,9 +155,6 @@ pub struct CreateOffer<'info> {
     /// NOTICE: required for crosschain offer
     pub enforced_options: Option<Account<'info, EnforcedOptions>>,
 
-    #[account(
-        constraint = token_program.key() == TOKEN_PROGRAM_ID // stick to spl token program for mvp
-    )]
     pub token_program: Option<Interface<'info, TokenInterface>>,
 
     pub associated_token_program: Option<Program<'info, AssociatedToken>>,
@@ -101,32 +168,96 @@ impl CreateOffer<'_> {
         params: &CreateOfferParams,
         fee: &MessagingFee
     ) -> Result<(CreateOfferReceipt, MessagingReceipt)> {
+        ctx.accounts.otc_config.assert_not_paused(&ctx.accounts.eid_pause)?;
+        ctx.accounts.otc_config.assert_token_extensions_allowed(
+            ctx.accounts.src_token_mint.as_ref()
+        )?;
+
         let src_token_address = OtcConfig::get_token_address(ctx.accounts.src_token_mint.as_ref());
 
-        let (src_amount_sd, src_amount_ld): (u64, u64);
+        let maker_fee_bps = ctx.accounts.otc_config.maker_fee_bps;
+
+        let (src_amount_sd, src_amount_ld, maker_fee_sd, maker_fee_ld): (u64, u64, u64, u64);
+        let (escrowed_amount_sd, escrowed_amount_ld): (u64, u64);
+        let (escrow_transfer_fee_ld, treasury_transfer_fee_ld): (u64, u64);
         {
-            let decimal_conversion_rate = OtcConfig::get_decimal_conversion_rate(
+            let local_decimals = OtcConfig::get_local_decimals(
                 ctx.accounts.src_token_mint.as_ref()
             );
             (src_amount_sd, src_amount_ld) = OtcConfig::remove_dust(
                 params.src_amount_ld,
-                decimal_conversion_rate
-            );
+                local_decimals
+            )?;
+            maker_fee_sd = OtcConfig::to_maker_fee(src_amount_sd, maker_fee_bps, local_decimals);
+            maker_fee_ld = OtcConfig::sd2ld(maker_fee_sd, local_decimals);
+
+            // token-2022 transfer fee is withheld from what the escrow and the treasury receive
+            escrow_transfer_fee_ld = OtcConfig::get_transfer_fee(
+                ctx.accounts.src_token_mint.as_ref(),
+                src_amount_ld - maker_fee_ld
+            )?;
+            treasury_transfer_fee_ld = OtcConfig::get_transfer_fee(
+                ctx.accounts.src_token_mint.as_ref(),
+                maker_fee_ld
+            )?;
+            escrowed_amount_ld = src_amount_ld - maker_fee_ld - escrow_transfer_fee_ld;
+            escrowed_amount_sd = OtcConfig::ld2sd(escrowed_amount_ld, local_decimals)?;
         }
 
         // validate pricing
-        require!(src_amount_sd != 0 && params.exchange_rate_sd != 0, OtcError::InvalidPricing);
+        require!(
+            escrowed_amount_sd != 0 && params.exchange_rate_sd != 0,
+            OtcError::InvalidPricing
+        );
+
+        // validate nonce
+        require!(params.nonce == ctx.accounts.seller_nonce.next(), OtcError::InvalidNonce);
+
+        // validate min fill
+        require!(params.min_fill_sd <= escrowed_amount_sd, OtcError::InvalidMinFill);
+
+        // validate allowed buyer, the zero address means any buyer on the wire
+        require!(
+            params.allowed_buyer != Some(<[u8; 32]>::default()),
+            OtcError::InvalidAllowedBuyer
+        );
+
+        // validate expiry
+        if let Some(expires_at) = params.expires_at {
+            require!(expires_at > Clock::get()?.unix_timestamp, OtcError::InvalidExpiry);
+        }
+
+        ctx.accounts.seller_nonce.nonce = params.nonce;
+        ctx.accounts.seller_nonce.bump = ctx.bumps.seller_nonce;
+
+        ctx.accounts.escrow.token_address = src_token_address;
+        ctx.accounts.escrow.bump = ctx.bumps.escrow;
 
         let offer: Offer = Offer {
             src_seller_address: ctx.accounts.seller.key().to_bytes(),
             dst_seller_address: params.dst_seller_address,
-            src_eid: OtcConfig::EID,
+            src_eid: ctx.accounts.otc_config.eid,
             dst_eid: params.dst_eid,
             src_token_address,
             dst_token_address: params.dst_token_address,
-            src_amount_sd,
+            src_amount_sd: escrowed_amount_sd, // maker fee and transfer fee are not escrowed
             exchange_rate_sd: params.exchange_rate_sd,
+            expires_at: params.expires_at,
+            nonce: params.nonce,
+            min_fill_sd: params.min_fill_sd,
+            all_or_nothing: params.all_or_nothing,
+            allowed_buyer: params.allowed_buyer,
+            buyers_root: params.buyers_root,
+            src_token_program: OtcConfig::get_token_program_address(
+                ctx.accounts.src_token_mint.as_ref()
+            ),
+            src_granularity_sd: OtcConfig::get_granularity_sd(
+                OtcConfig::get_local_decimals(ctx.accounts.src_token_mint.as_ref())
+            ),
+            cancel_pending: false,
+            price_revision: 0,
 
+            payer: ctx.accounts.seller.key(),
             bump: ctx.bumps.offer,
         };
 
@@ -144,11 +275,17 @@ impl CreateOffer<'_> {
             dst_token_address: offer.dst_token_address,
             src_amount_sd: offer.src_amount_sd,
             exchange_rate_sd: offer.exchange_rate_sd,
+            expires_at: offer.expires_at,
+            nonce: offer.nonce,
+            min_fill_sd: offer.min_fill_sd,
+            all_or_nothing: offer.all_or_nothing,
+            allowed_buyer: offer.allowed_buyer,
+            buyers_root: offer.buyers_root,
         });
 
         let mut receipt = MessagingReceipt::default();
 
-        if params.dst_eid != OtcConfig::EID {
+        if params.dst_eid != ctx.accounts.otc_config.eid {
             // crosschain offer
 
             let peer = ctx.accounts.peer.as_ref().expect(OtcConfig::ERROR_MSG);
@@ -174,9 +311,10 @@ impl CreateOffer<'_> {
             )?;
         }
 
+        // (amount - maker fee) to escrow
         OtcConfig::transfer(
             ctx.accounts.seller.as_ref(),
-            src_amount_ld,
+            src_amount_ld - maker_fee_ld,
             Some(&ctx.accounts.escrow.to_account_info()),
             ctx.accounts.token_program.as_ref(),
             ctx.accounts.src_seller_ata.as_ref(),
@@ -185,10 +323,54 @@ impl CreateOffer<'_> {
             None
         )?;
 
+        // maker fee to treasury
+        if maker_fee_ld != 0 {
+            require!(
+                ctx.accounts.src_token_mint.is_none() || ctx.accounts.src_treasury_ata.is_some(),
+                OtcError::MissingAccount
+            );
+
+            OtcConfig::transfer(
+                ctx.accounts.seller.as_ref(),
+                maker_fee_ld,
+                Some(&ctx.accounts.treasury.to_account_info()),
+                ctx.accounts.token_program.as_ref(),
+                ctx.accounts.src_seller_ata.as_ref(),
+                ctx.accounts.src_token_mint.as_ref(),
+                ctx.accounts.src_treasury_ata.as_deref(),
+                None
+            )?;
+
+            ctx.accounts.fee_ledger
+                .as_mut()
+                .ok_or(OtcError::MissingAccount)?
+                .collect(
+                    &src_token_address,
+                    ctx.bumps.fee_ledger,
+                    maker_fee_ld - treasury_transfer_fee_ld
+                );
+        }
+
+        ctx.accounts.escrow_ledger.vaults += 1;
+
+        // dust below shared decimals is not owed to the offer
+        ctx.accounts.escrow_ledger.deposit(
+            &src_token_address,
+            ctx.bumps.escrow_ledger,
+            OtcConfig::sd2ld(
+                escrowed_amount_sd,
+                OtcConfig::get_local_decimals(ctx.accounts.src_token_mint.as_ref())
+            )
+        );
+
         Ok((
             CreateOfferReceipt {
                 offer_id,
                 src_amount_ld,
+                maker_fee_ld,
+                maker_fee_bps,
+                escrowed_amount_ld,
+                transfer_fee_ld: escrow_transfer_fee_ld + treasury_transfer_fee_ld,
             },
             receipt,
         ))
@@ -202,10 +384,30 @@ pub struct CreateOfferParams {
     pub dst_token_address: [u8; 32],
     pub src_amount_ld: u64,
     pub exchange_rate_sd: u64,
+    /// NOTICE: unix timestamp, the offer can't be accepted from then on and can be reclaimed by anyone
+    pub expires_at: Option<i64>,
+    /// NOTICE: next nonce of the seller, see [SellerNonce]
+    pub nonce: u64,
+    /// NOTICE: minimum src amount of a single accept, 0 - no minimum
+    pub min_fill_sd: u64,
+    /// NOTICE: the offer can't be partially filled
+    pub all_or_nothing: bool,
+    /// NOTICE: private offer - only this dst buyer address can accept it
+    pub allowed_buyer: Option<[u8; 32]>,
+    /// NOTICE: allowlisted offer - merkle root of keccak(dst_buyer_address, max_fill_sd) leaves,
+    /// max_fill_sd 0 - no cap for the buyer
+    pub buyers_root: Option<[u8; 32]>,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct CreateOfferReceipt {
     pub offer_id: [u8; 32],
+    /// NOTICE: total taken from the seller, maker fee included
     pub src_amount_ld: u64,
+    pub maker_fee_ld: u64,
+    pub maker_fee_bps: u16,
+    /// NOTICE: received by the escrow, net of the maker fee and the token-2022 transfer fee
+    pub escrowed_amount_ld: u64,
+    /// NOTICE: token-2022 transfer fee withheld from the escrow and the treasury
+    pub transfer_fee_ld: u64,
 }
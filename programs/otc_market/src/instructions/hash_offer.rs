@@ -10,7 +10,8 @@ impl HashOffer {
         dst_eid: u32,
         src_token_address: &[u8; 32],
         dst_token_address: &[u8; 32],
-        exchange_rate_sd: u64
+        exchange_rate_sd: u64,
+        nonce: u64
     ) -> Result<[u8; 32]> {
         Ok(
             Offer::hash_offer(
@@ -19,7 +20,8 @@ impl HashOffer {
                 dst_eid,
                 src_token_address,
                 dst_token_address,
-                exchange_rate_sd
+                exchange_rate_sd,
+                nonce
             )
         )
     }
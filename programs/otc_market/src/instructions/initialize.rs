@@ -26,21 +26,30 @@ pub struct Initialize<'info> {
     #[account(
         init,
         payer = payer,
-        space = 8 + Escrow::INIT_SPACE,
-        seeds = [Escrow::ESCROW_SEED],
+        space = 8 + Treasury::INIT_SPACE,
+        seeds = [Treasury::TREASURY_SEED],
         bump
     )]
-    pub escrow: Account<'info, Escrow>,
+    pub treasury: Account<'info, Treasury>,
 
     pub system_program: Program<'info, System>,
 }
 
 impl Initialize<'_> {
     pub fn apply(ctx: &mut Context<Initialize>, params: &InitializeParams) -> Result<()> {
+        require!(params.eid != 0, OtcError::InvalidEid);
+        // same rules as set_treasury
+        require!(
+            params.treasury != Pubkey::default() &&
+                params.treasury != ctx.accounts.otc_config.key() &&
+                params.treasury != ctx.accounts.treasury.key(),
+            OtcError::InvalidTreasury
+        );
+
         ctx.accounts.otc_config.bump = ctx.bumps.otc_config;
         ctx.accounts.otc_config.treasury = params.treasury;
 
-        ctx.accounts.escrow.bump = ctx.bumps.escrow;
+        ctx.accounts.treasury.bump = ctx.bumps.treasury;
 
         ctx.accounts.lz_receive_types_accounts.otc_config = ctx.accounts.otc_config.key();
 
@@ -48,6 +57,7 @@ impl Initialize<'_> {
 
         ctx.accounts.otc_config.init(
             params.endpoint_program,
+            params.eid,
             ctx.accounts.payer.key(),
             ctx.remaining_accounts,
             oapp_signer,
@@ -59,5 +69,8 @@ impl Initialize<'_> {
 pub struct InitializeParams {
     pub endpoint_program: Option<Pubkey>,
 
+    /// NOTICE: local eid of the cluster the program is deployed to (e.g. 40168 for solana devnet)
+    pub eid: u32,
+
     pub treasury: Pubkey,
 }
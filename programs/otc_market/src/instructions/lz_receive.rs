@@ -28,6 +28,10 @@ pub struct LzReceive<'info> {
     #[account(seeds = [OtcConfig::OTC_SEED], bump = otc_config.bump)]
     pub otc_config: Account<'info, OtcConfig>,
 
+    #[account(seeds = [EidPause::PAUSE_SEED, otc_config.key().as_ref(), &params.src_eid.to_be_bytes()], bump)]
+    /// CHECK: eid pause switch, may be uninitialized
+    pub eid_pause: AccountInfo<'info>,
+
     #[account(
         init_if_needed,
         payer = payer,
@@ -37,7 +41,26 @@ pub struct LzReceive<'info> {
     )]
     pub offer: Account<'info, Offer>,
 
-    /// NOTICE: required for offer cancel order message
+    /// NOTICE: required for offer accepted message that fully fills the offer, offer cancel order & reduce order message
+
+    #[account(
+        mut,
+        constraint = offer_payer.key() == offer.payer @ OtcError::InvalidOfferPayer
+    )]
+    /// CHECK: asserted against the one stored in the offer
+    pub offer_payer: Option<AccountInfo<'info>>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::authority = offer_payer,
+        associated_token::mint = src_token_mint,
+        associated_token::token_program = token_program
+    )]
+    /// NOTICE: src spl token - rounding dust of the closed escrow vault: to_ata
+    pub src_seller_ata: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    /// NOTICE: required for offer cancel order, reduce order & topped up message
 
     #[account(
         seeds = [
@@ -66,9 +89,16 @@ pub struct LzReceive<'info> {
 
     /// NOTICE: required for offer accepted & offer canceled message
 
-    #[account(mut, seeds = [Escrow::ESCROW_SEED], bump = escrow.bump)]
+    #[account(mut, seeds = [Escrow::ESCROW_SEED, &offer_id(&params.message)], bump = escrow.bump)]
     pub escrow: Option<Box<Account<'info, Escrow>>>,
 
+    #[account(
+        mut,
+        seeds = [EscrowLedger::ESCROW_LEDGER_SEED, offer.src_token_address.as_ref()],
+        bump = escrow_ledger.bump
+    )]
+    pub escrow_ledger: Option<Box<Account<'info, EscrowLedger>>>,
+
     #[account(
         mut,
         associated_token::authority = escrow,
@@ -78,6 +108,7 @@ pub struct LzReceive<'info> {
     pub src_escrow_ata: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
 
     #[account(
+        mut,
         mint::token_program = token_program,
         constraint = src_token_mint.key() == Pubkey::new_from_array(offer.src_token_address) @ OtcError::InvalidSrcTokenMint
     )]
@@ -96,6 +127,31 @@ impl LzReceive<'_> {
     pub fn apply(ctx: &mut Context<LzReceive>, params: &LzReceiveParams) -> Result<()> {
         let msg_type = get_message_type(&params.message)?;
 
+        // paused messages are not cleared and can be retried once unpaused
+        match msg_type {
+            Message::OfferCreated |
+            Message::OfferAccepted |
+            Message::OfferToppedUp |
+            Message::OfferRepriced => {
+                ctx.accounts.otc_config.assert_not_paused(&ctx.accounts.eid_pause)?;
+            }
+            Message::OfferCancelOrder |
+            Message::OfferCanceled |
+            Message::OfferReduceOrder |
+            Message::OfferReduced => {
+                ctx.accounts.otc_config.assert_cancellations_not_paused(&ctx.accounts.eid_pause)?;
+            }
+        }
+
+        // only offer created opens the offer, any later message of a closed offer would
+        // otherwise act on the blank account init_if_needed just created
+        // NOTICE: a top up of a closed copy is bounced back to the src chain instead
+        require!(
+            matches!(msg_type, Message::OfferCreated | Message::OfferToppedUp) ||
+                ctx.accounts.offer.is_initialized(),
+            OtcError::OfferNotFound
+        );
+
         match msg_type {
             Message::OfferCreated => {
                 receive_offer_created(ctx, &params.message)?;
@@ -109,6 +165,18 @@ impl LzReceive<'_> {
             Message::OfferCanceled => {
                 receive_offer_canceled(ctx, &params.message)?;
             }
+            Message::OfferToppedUp => {
+                receive_offer_topped_up(ctx, &params.message)?;
+            }
+            Message::OfferReduceOrder => {
+                receive_offer_reduce_order(ctx, &params.message)?;
+            }
+            Message::OfferReduced => {
+                receive_offer_reduced(ctx, &params.message)?;
+            }
+            Message::OfferRepriced => {
+                receive_offer_repriced(ctx, &params.message)?;
+            }
         }
 
         // clear
@@ -129,4 +197,19 @@ impl LzReceive<'_> {
 
         Ok(())
     }
+
+    /// src tokens released by a message only go to the src seller or buyer it was meant for
+    pub fn assert_src_actor(&self, src_actor_address: &[u8; 32], error: OtcError) -> Result<()> {
+        let src_actor = self.src_actor.as_ref().ok_or(error)?;
+
+        let is_src_actor =
+            src_actor.key() == Pubkey::new_from_array(*src_actor_address) &&
+            (self.src_token_mint.is_none() ||
+                matches!(self.src_actor_ata.as_ref(), Some(ata) if ata.owner == src_actor.key()));
+        if !is_src_actor {
+            return Err(error.into());
+        }
+
+        Ok(())
+    }
 }
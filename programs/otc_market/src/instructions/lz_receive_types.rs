@@ -21,6 +21,14 @@ impl LzReceiveTypes<'_> {
             ],
             ctx.program_id
         );
+        let (eid_pause, _) = Pubkey::find_program_address(
+            &[
+                EidPause::PAUSE_SEED,
+                ctx.accounts.otc_config.key().as_ref(),
+                &params.src_eid.to_be_bytes(),
+            ],
+            ctx.program_id
+        );
 
         let mut accounts = vec![
             LzAccount {
@@ -37,6 +45,11 @@ impl LzReceiveTypes<'_> {
                 pubkey: ctx.accounts.otc_config.key(),
                 is_signer: false,
                 is_writable: false,
+            },
+            LzAccount {
+                pubkey: eid_pause,
+                is_signer: false,
+                is_writable: false,
             }
         ];
 
@@ -47,6 +60,12 @@ impl LzReceiveTypes<'_> {
             Message::OfferCancelOrder =>
                 receive_offer_cancel_order_types(ctx, &params.message, params.src_eid),
             Message::OfferCanceled => receive_offer_canceled_types(ctx, &params.message),
+            Message::OfferToppedUp =>
+                receive_offer_topped_up_types(ctx, &params.message, params.src_eid),
+            Message::OfferReduceOrder =>
+                receive_offer_reduce_order_types(ctx, &params.message, params.src_eid),
+            Message::OfferReduced => receive_offer_reduced_types(ctx, &params.message),
+            Message::OfferRepriced => receive_offer_repriced_types(ctx, &params.message),
         };
         accounts.extend_from_slice(&ix_accounts);
 
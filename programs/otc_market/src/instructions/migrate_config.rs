@@ -0,0 +1,68 @@
+use crate::*;
+use anchor_lang::{ system_program::{ transfer, Transfer }, Discriminator };
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct MigrateConfig<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(mut, seeds = [OtcConfig::OTC_SEED], bump, owner = crate::ID)]
+    /// CHECK: legacy config layout, deserialized in apply
+    pub otc_config: AccountInfo<'info>,
+
+    // NOTICE: init_if_needed so a rerun on a migrated config fails on InvalidLegacyConfig
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = 8 + Treasury::INIT_SPACE,
+        seeds = [Treasury::TREASURY_SEED],
+        bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl MigrateConfig<'_> {
+    /// NOTICE: carries the config created before roles, fees, pauses and the stored local eid over to the
+    /// current layout - every other instruction loading the config, migrate_offer included, fails until then
+    pub fn apply(ctx: &mut Context<MigrateConfig>) -> Result<()> {
+        let legacy_config: LegacyOtcConfig;
+        {
+            let data = ctx.accounts.otc_config.try_borrow_data()?;
+            require!(
+                data.len() == 8 + LegacyOtcConfig::SIZE && data[..8] == OtcConfig::DISCRIMINATOR,
+                OtcError::InvalidLegacyConfig
+            );
+            legacy_config = LegacyOtcConfig::deserialize(&mut &data[8..])?;
+        }
+        require!(legacy_config.admin == ctx.accounts.admin.key(), OtcError::Unauthorized);
+
+        let otc_config = legacy_config.migrate();
+
+        // the admin funds the rent of the grown account
+        let otc_config_info = ctx.accounts.otc_config.to_account_info();
+        let space = 8 + OtcConfig::INIT_SPACE;
+        let rent = Rent::get()?.minimum_balance(space).saturating_sub(otc_config_info.lamports());
+        if rent != 0 {
+            transfer(
+                CpiContext::new(ctx.accounts.system_program.to_account_info(), Transfer {
+                    from: ctx.accounts.admin.to_account_info(),
+                    to: otc_config_info.clone(),
+                }),
+                rent
+            )?;
+        }
+        otc_config_info.realloc(space, true)?;
+        otc_config.try_serialize(&mut &mut otc_config_info.try_borrow_mut_data()?[..])?;
+
+        ctx.accounts.treasury.bump = ctx.bumps.treasury;
+
+        emit_cpi!(ConfigMigrated {
+            eid: otc_config.eid,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,206 @@
+use crate::*;
+use anchor_lang::{ system_program::{ transfer, Transfer }, Discriminator };
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::ID as TOKEN_PROGRAM_ID,
+    token_interface::{ Mint, TokenAccount, TokenInterface },
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(offer_id: [u8; 32])]
+pub struct MigrateOffer<'info> {
+    /// NOTICE: the src seller migrates its own offer, the admin migrates a dst copy - see [MigrateOffer::apply]
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(seeds = [OtcConfig::OTC_SEED], bump = otc_config.bump)]
+    pub otc_config: Account<'info, OtcConfig>,
+
+    #[account(mut, seeds = [&offer_id], bump, owner = crate::ID)]
+    /// CHECK: legacy offer layout, deserialized in apply
+    pub offer: AccountInfo<'info>,
+
+    /// src - NOTICE: required for src offer
+
+    #[account(mut, seeds = [Escrow::ESCROW_SEED], bump)]
+    /// CHECK: legacy escrow shared by all offers
+    /// NOTICE: src sol offer - escrowed amount: from | src spl offer - escrowed amount: authority of from_ata
+    pub legacy_escrow: Option<AccountInfo<'info>>,
+
+    #[account(
+        mut,
+        associated_token::authority = legacy_escrow,
+        associated_token::mint = src_token_mint,
+        associated_token::token_program = token_program
+    )]
+    /// NOTICE: required for src spl offer - from_ata
+    pub legacy_escrow_ata: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + Escrow::INIT_SPACE,
+        seeds = [Escrow::ESCROW_SEED.as_ref(), &offer_id],
+        bump
+    )]
+    /// NOTICE: src sol offer - escrowed amount: to | src spl offer - escrowed amount: authority of to_ata
+    pub escrow: Option<Box<Account<'info, Escrow>>>,
+
+    #[account(
+        init,
+        payer = payer,
+        associated_token::authority = escrow,
+        associated_token::mint = src_token_mint,
+        associated_token::token_program = token_program
+    )]
+    /// NOTICE: required for src spl offer - to_ata
+    pub src_escrow_ata: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + EscrowLedger::INIT_SPACE,
+        seeds = [
+            EscrowLedger::ESCROW_LEDGER_SEED,
+            OtcConfig::get_token_address(src_token_mint.as_deref()).as_ref(),
+        ],
+        bump
+    )]
+    pub escrow_ledger: Option<Box<Account<'info, EscrowLedger>>>,
+
+    #[account(mint::token_program = token_program)]
+    /// NOTICE: required for src spl offer
+    pub src_token_mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+
+    pub associated_token_program: Option<Program<'info, AssociatedToken>>,
+
+    /// NOTICE: required for src spl offer
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl MigrateOffer<'_> {
+    /// NOTICE: carries an offer created before nonces over to the current layout, it keeps its id (nonce 0)
+    /// and a src offer moves its escrowed amount out of the legacy shared escrow into a vault of its own
+    /// NOTICE: whoever migrates pays the grown offer and its vault and is recorded as the offer payer, so the
+    /// rent returns to who paid it - the src seller for its own offer (closes pay the src seller, see the
+    /// offer_payer of receive_offer_accepted_types), the admin for a dst copy, which only a fill closes
+    pub fn apply(ctx: &mut Context<MigrateOffer>, offer_id: &[u8; 32]) -> Result<()> {
+        let legacy_offer: LegacyOffer;
+        {
+            let data = ctx.accounts.offer.try_borrow_data()?;
+            require!(
+                data.len() == 8 + LegacyOffer::SIZE && data[..8] == Offer::DISCRIMINATOR,
+                OtcError::InvalidLegacyOffer
+            );
+            legacy_offer = LegacyOffer::deserialize(&mut &data[8..])?;
+        }
+
+        let is_src_offer = legacy_offer.src_eid == ctx.accounts.otc_config.eid;
+        require!(
+            ctx.accounts.payer.key() ==
+                (if is_src_offer {
+                    Pubkey::new_from_array(legacy_offer.src_seller_address)
+                } else {
+                    ctx.accounts.otc_config.admin
+                }),
+            OtcError::Unauthorized
+        );
+
+        let offer = Offer {
+            src_seller_address: legacy_offer.src_seller_address,
+            dst_seller_address: legacy_offer.dst_seller_address,
+            src_eid: legacy_offer.src_eid,
+            dst_eid: legacy_offer.dst_eid,
+            src_token_address: legacy_offer.src_token_address,
+            dst_token_address: legacy_offer.dst_token_address,
+            src_amount_sd: legacy_offer.src_amount_sd,
+            exchange_rate_sd: legacy_offer.exchange_rate_sd,
+            expires_at: None,
+            nonce: 0,
+            min_fill_sd: 0,
+            all_or_nothing: false,
+            allowed_buyer: None,
+            buyers_root: None,
+            // legacy offers only supported spl token mints with at least SHARED_DECIMALS,
+            // the program of a non-solana src token is unknown
+            src_token_program: if
+                is_src_offer &&
+                legacy_offer.src_token_address != <[u8; 32]>::default()
+            {
+                TOKEN_PROGRAM_ID.to_bytes()
+            } else {
+                <[u8; 32]>::default()
+            },
+            src_granularity_sd: 1,
+            cancel_pending: false,
+            price_revision: 0,
+
+            payer: ctx.accounts.payer.key(),
+            bump: legacy_offer.bump,
+        };
+
+        // the payer funds the rent of the grown account
+        let offer_info = ctx.accounts.offer.to_account_info();
+        let space = 8 + Offer::INIT_SPACE;
+        let rent = Rent::get()?.minimum_balance(space).saturating_sub(offer_info.lamports());
+        if rent != 0 {
+            transfer(
+                CpiContext::new(ctx.accounts.system_program.to_account_info(), Transfer {
+                    from: ctx.accounts.payer.to_account_info(),
+                    to: offer_info.clone(),
+                }),
+                rent
+            )?;
+        }
+        offer_info.realloc(space, true)?;
+        offer.try_serialize(&mut &mut offer_info.try_borrow_mut_data()?[..])?;
+
+        if is_src_offer {
+            // src offer
+            let src_token_mint = ctx.accounts.src_token_mint.as_deref();
+            require!(
+                OtcConfig::get_token_address(src_token_mint) == offer.src_token_address,
+                OtcError::InvalidSrcTokenMint
+            );
+
+            let legacy_escrow = ctx.accounts.legacy_escrow
+                .as_ref()
+                .ok_or(OtcError::MissingAccount)?;
+            let escrow = ctx.accounts.escrow.as_mut().ok_or(OtcError::MissingAccount)?;
+            escrow.token_address = offer.src_token_address;
+            escrow.bump = ctx.bumps.escrow;
+
+            let src_amount_ld = OtcConfig::sd2ld(
+                offer.src_amount_sd,
+                OtcConfig::get_local_decimals(src_token_mint)
+            );
+
+            // legacy escrow -> escrow vault of the offer
+            OtcConfig::transfer(
+                legacy_escrow,
+                src_amount_ld,
+                Some(&escrow.to_account_info()),
+                ctx.accounts.token_program.as_ref(),
+                ctx.accounts.legacy_escrow_ata.as_deref(),
+                src_token_mint,
+                ctx.accounts.src_escrow_ata.as_deref(),
+                Some(&[&[Escrow::ESCROW_SEED, &[ctx.bumps.legacy_escrow]]])
+            )?;
+
+            let escrow_ledger = ctx.accounts.escrow_ledger
+                .as_mut()
+                .ok_or(OtcError::MissingAccount)?;
+            escrow_ledger.vaults += 1;
+            escrow_ledger.deposit(&offer.src_token_address, ctx.bumps.escrow_ledger, src_amount_ld);
+        }
+
+        emit_cpi!(OfferMigrated {
+            offer_id: *offer_id,
+        });
+
+        Ok(())
+    }
+}
@@ -18,6 +18,34 @@ pub mod quote_cancel_offer;
 pub mod receive_offer_cancel_order;
 pub mod receive_offer_canceled;
 pub mod close;
+pub mod propose_admin;
+pub mod accept_admin;
+pub mod set_role;
+pub mod set_treasury;
+pub mod withdraw_fees;
+pub mod set_fee;
+pub mod set_token_fee;
+pub mod set_eid_fee;
+pub mod set_referrer;
+pub mod set_pause;
+pub mod set_eid_pause;
+pub mod reclaim_expired_offer;
+pub mod migrate_config;
+pub mod migrate_offer;
+pub mod top_up_offer;
+pub mod quote_top_up_offer;
+pub mod receive_offer_topped_up;
+pub mod reduce_offer;
+pub mod quote_reduce_offer;
+pub mod receive_offer_reduce_order;
+pub mod receive_offer_reduced;
+pub mod close_buyer_fill;
+pub mod update_offer_price;
+pub mod quote_update_offer_price;
+pub mod receive_offer_repriced;
+pub mod set_token_extension_policy;
+pub mod verify_escrow;
+pub mod sweep_escrow_surplus;
 
 pub use accept_offer::*;
 pub use cancel_offer::*;
@@ -39,3 +67,31 @@ pub use quote_cancel_offer::*;
 pub use receive_offer_cancel_order::*;
 pub use receive_offer_canceled::*;
 pub use close::*;
+pub use propose_admin::*;
+pub use accept_admin::*;
+pub use set_role::*;
+pub use set_treasury::*;
+pub use withdraw_fees::*;
+pub use set_fee::*;
+pub use set_token_fee::*;
+pub use set_eid_fee::*;
+pub use set_referrer::*;
+pub use set_pause::*;
+pub use set_eid_pause::*;
+pub use reclaim_expired_offer::*;
+pub use migrate_config::*;
+pub use migrate_offer::*;
+pub use top_up_offer::*;
+pub use quote_top_up_offer::*;
+pub use receive_offer_topped_up::*;
+pub use reduce_offer::*;
+pub use quote_reduce_offer::*;
+pub use receive_offer_reduce_order::*;
+pub use receive_offer_reduced::*;
+pub use close_buyer_fill::*;
+pub use update_offer_price::*;
+pub use quote_update_offer_price::*;
+pub use receive_offer_repriced::*;
+pub use set_token_extension_policy::*;
+pub use verify_escrow::*;
+pub use sweep_escrow_surplus::*;
@@ -0,0 +1,30 @@
+use crate::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ProposeAdmin<'info> {
+    #[account(constraint = otc_config.admin == admin.key() @ OtcError::Unauthorized)]
+    pub admin: Signer<'info>,
+
+    #[account(mut, seeds = [OtcConfig::OTC_SEED], bump = otc_config.bump)]
+    pub otc_config: Account<'info, OtcConfig>,
+}
+
+impl ProposeAdmin<'_> {
+    pub fn apply(ctx: &mut Context<ProposeAdmin>, params: &ProposeAdminParams) -> Result<()> {
+        // None revokes a pending proposal
+        ctx.accounts.otc_config.pending_admin = params.pending_admin;
+
+        emit_cpi!(AdminProposed {
+            admin: ctx.accounts.otc_config.admin,
+            pending_admin: params.pending_admin,
+        });
+
+        Ok(())
+    }
+}
+
+#[derive(Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct ProposeAdminParams {
+    pub pending_admin: Option<Pubkey>,
+}
@@ -11,15 +11,54 @@ pub struct QuoteAcceptOffer<'info> {
     #[account(
         seeds = [&params.offer_id], 
         bump = offer.bump,
-        constraint = offer.dst_eid == OtcConfig::EID @ OtcError::InvalidEid,
+        constraint = offer.dst_eid == otc_config.eid @ OtcError::InvalidEid,
         constraint = offer.src_amount_sd >= params.src_amount_sd @ OtcError::ExcessiveAmount
     )]
     pub offer: Account<'info, Offer>,
 
+    #[account(
+        seeds = [
+            FeeOverride::TOKEN_FEE_SEED.as_ref(),
+            otc_config.key().as_ref(),
+            offer.dst_token_address.as_ref(),
+        ],
+        bump
+    )]
+    /// CHECK: dst token fee override, may be uninitialized
+    pub token_fee: AccountInfo<'info>,
+
+    #[account(
+        seeds = [
+            FeeOverride::EID_FEE_SEED.as_ref(),
+            otc_config.key().as_ref(),
+            &offer.src_eid.to_be_bytes(),
+        ],
+        bump
+    )]
+    /// CHECK: src eid fee override, may be uninitialized - the dst eid of an acceptable offer is always the local eid
+    pub eid_fee: AccountInfo<'info>,
+
+    #[account(
+        seeds = [
+            Referrer::REFERRER_SEED.as_ref(),
+            params.referrer.unwrap_or_default().as_ref(),
+        ],
+        bump = referrer_config.bump
+    )]
+    /// NOTICE: required for referred accept
+    pub referrer_config: Option<Account<'info, Referrer>>,
+
+    #[account(
+        seeds = [BuyerFill::BUYER_FILL_SEED.as_ref(), &params.offer_id, dst_buyer_address.as_ref()],
+        bump = buyer_fill.bump
+    )]
+    /// NOTICE: required for allowlisted offer the buyer already filled
+    pub buyer_fill: Option<Account<'info, BuyerFill>>,
+
     #[account(
         mint::token_program = token_program,
         constraint = dst_token_mint.key() == Pubkey::new_from_array(offer.dst_token_address) @ OtcError::InvalidDstTokenMint,
-        constraint = dst_token_mint.decimals >= OtcConfig::SHARED_DECIMALS @ OtcError::InvalidLocalDecimals
+        constraint = dst_token_mint.decimals <= OtcConfig::MAX_LOCAL_DECIMALS @ OtcError::InvalidLocalDecimals
     )]
     /// NOTICE: required for dst spl token - token_mint
     pub dst_token_mint: Option<InterfaceAccount<'info, Mint>>,
@@ -51,8 +90,64 @@ impl QuoteAcceptOffer<'_> {
         params: &AcceptOfferParams,
         pay_in_lz_token: bool
     ) -> Result<(AcceptOfferReceipt, MessagingFee)> {
+        ctx.accounts.otc_config.assert_token_extensions_allowed(
+            ctx.accounts.dst_token_mint.as_ref()
+        )?;
+
+        require!(
+            !ctx.accounts.offer.is_expired(Clock::get()?.unix_timestamp),
+            OtcError::OfferExpired
+        );
+
+        require!(
+            ctx.accounts.offer.is_fill_allowed(params.src_amount_sd),
+            OtcError::InvalidFillAmount
+        );
+
+        // the offer may have been repriced since the buyer quoted it
+        require!(
+            ctx.accounts.offer.exchange_rate_sd <= params.max_exchange_rate_sd,
+            OtcError::ExcessiveExchangeRate
+        );
+
+        require!(
+            ctx.accounts.offer.is_buyer_allowed(dst_buyer_address),
+            OtcError::OnlyAllowedBuyer
+        );
+
+        require!(
+            ctx.accounts.offer.is_buyer_listed(
+                dst_buyer_address,
+                params.buyer_max_fill_sd,
+                &params.buyer_proof
+            ),
+            OtcError::InvalidBuyerProof
+        );
+
+        if ctx.accounts.offer.buyers_root.is_some() && params.buyer_max_fill_sd != 0 {
+            let filled_sd = ctx.accounts.buyer_fill
+                .as_ref()
+                .map_or(0, |buyer_fill| buyer_fill.filled_sd);
+            require!(
+                filled_sd
+                    .checked_add(params.src_amount_sd)
+                    .is_some_and(|filled_sd| filled_sd <= params.buyer_max_fill_sd),
+                OtcError::ExcessiveBuyerFill
+            );
+        }
+
+        if params.referrer.is_some() {
+            let referrer_config = ctx.accounts.referrer_config
+                .as_ref()
+                .ok_or(OtcError::InvalidReferrer)?;
+            require!(
+                params.referrer_share_bps <= referrer_config.max_share_bps,
+                OtcError::ExcessiveReferrerShare
+            );
+        }
+
         let messaging_fee: MessagingFee;
-        if ctx.accounts.offer.src_eid != OtcConfig::EID {
+        if ctx.accounts.offer.src_eid != ctx.accounts.otc_config.eid {
             // crosschain
             let peer = ctx.accounts.peer.as_ref().expect(OtcConfig::ERROR_MSG);
             let enforced_options = ctx.accounts.enforced_options
@@ -63,7 +158,10 @@ impl QuoteAcceptOffer<'_> {
                 &params.offer_id,
                 params.src_amount_sd,
                 &params.src_buyer_address,
-                dst_buyer_address
+                dst_buyer_address,
+                &ctx.accounts.offer.src_token_address,
+                &ctx.accounts.offer.src_seller_address,
+                &ctx.accounts.offer.src_token_program
             );
 
             messaging_fee = oapp::endpoint_cpi::quote(
@@ -83,13 +181,24 @@ impl QuoteAcceptOffer<'_> {
             messaging_fee = MessagingFee::default();
         }
 
-        Ok((
-            OtcConfig::to_dst_amount(
-                params.src_amount_sd,
-                ctx.accounts.offer.exchange_rate_sd,
-                ctx.accounts.dst_token_mint.as_ref()
-            ),
-            messaging_fee,
-        ))
+        let taker_fee_bps = ctx.accounts.otc_config.get_taker_fee_bps(
+            &ctx.accounts.token_fee,
+            &ctx.accounts.eid_fee
+        )?;
+
+        let accept_offer_receipt = OtcConfig::to_dst_amount(
+            params.src_amount_sd,
+            ctx.accounts.offer.exchange_rate_sd,
+            ctx.accounts.dst_token_mint.as_ref(),
+            taker_fee_bps,
+            params.get_referrer_share_bps()
+        )?;
+
+        require!(
+            accept_offer_receipt.dst_amount_ld <= params.max_dst_amount_ld,
+            OtcError::SlippageExceeded
+        );
+
+        Ok((accept_offer_receipt, messaging_fee))
     }
 }
@@ -9,7 +9,7 @@ pub struct QuoteCancelOffer<'info> {
 
     #[account(
         seeds = [&offer_id], bump = offer.bump,
-        constraint = offer.dst_eid == OtcConfig::EID @ OtcError::InvalidEid,
+        constraint = offer.dst_eid == otc_config.eid @ OtcError::InvalidEid,
         constraint = offer.src_eid != offer.dst_eid @ OtcError::NotCrosschainOffer
     )]
     pub offer: Account<'info, Offer>,
@@ -35,7 +35,12 @@ pub struct QuoteCancelOffer<'info> {
 
 impl QuoteCancelOffer<'_> {
     pub fn apply(ctx: &mut Context<QuoteCancelOffer>, offer_id: &[u8; 32]) -> Result<MessagingFee> {
-        let payload = build_cancel_offer_payload(&offer_id);
+        let payload = build_cancel_offer_payload(
+            &offer_id,
+            &ctx.accounts.offer.src_seller_address,
+            &ctx.accounts.offer.src_token_address,
+            &ctx.accounts.offer.src_token_program
+        );
 
         let messaging_fee = oapp::endpoint_cpi::quote(
             ctx.accounts.otc_config.endpoint_program,
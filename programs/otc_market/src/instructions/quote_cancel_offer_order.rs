@@ -10,7 +10,7 @@ pub struct QuoteCancelOfferOrder<'info> {
     #[account(
         seeds = [&offer_id], bump = offer.bump,
         constraint = offer.src_seller_address == src_seller_address @ OtcError::OnlySeller,
-        constraint = offer.src_eid == OtcConfig::EID @ OtcError::InvalidEid,
+        constraint = offer.src_eid == otc_config.eid @ OtcError::InvalidEid,
         constraint = offer.src_eid != offer.dst_eid @ OtcError::NotCrosschainOffer
     )]
     pub offer: Account<'info, Offer>,
@@ -10,7 +10,7 @@ pub struct QuoteCreateOffer<'info> {
 
     #[account(
         mint::token_program = token_program,         
-        constraint = src_token_mint.decimals >= OtcConfig::SHARED_DECIMALS @ OtcError::InvalidLocalDecimals
+        constraint = src_token_mint.decimals <= OtcConfig::MAX_LOCAL_DECIMALS @ OtcError::InvalidLocalDecimals
     )]
     /// NOTICE: required for src spl offer
     pub src_token_mint: Option<InterfaceAccount<'info, Mint>>,
@@ -43,33 +43,73 @@ impl QuoteCreateOffer<'_> {
         params: &CreateOfferParams,
         pay_in_lz_token: bool
     ) -> Result<(CreateOfferReceipt, MessagingFee)> {
+        ctx.accounts.otc_config.assert_token_extensions_allowed(
+            ctx.accounts.src_token_mint.as_ref()
+        )?;
+
         let src_token_address = OtcConfig::get_token_address(ctx.accounts.src_token_mint.as_ref());
 
-        let (src_amount_sd, src_amount_ld): (u64, u64);
+        let maker_fee_bps = ctx.accounts.otc_config.maker_fee_bps;
+
+        let (src_amount_sd, src_amount_ld, maker_fee_sd, maker_fee_ld): (u64, u64, u64, u64);
+        let (escrowed_amount_sd, escrowed_amount_ld): (u64, u64);
+        let (escrow_transfer_fee_ld, treasury_transfer_fee_ld): (u64, u64);
         {
-            let decimal_conversion_rate = OtcConfig::get_decimal_conversion_rate(
+            let local_decimals = OtcConfig::get_local_decimals(
                 ctx.accounts.src_token_mint.as_ref()
             );
             (src_amount_sd, src_amount_ld) = OtcConfig::remove_dust(
                 params.src_amount_ld,
-                decimal_conversion_rate
-            );
+                local_decimals
+            )?;
+            maker_fee_sd = OtcConfig::to_maker_fee(src_amount_sd, maker_fee_bps, local_decimals);
+            maker_fee_ld = OtcConfig::sd2ld(maker_fee_sd, local_decimals);
+
+            // token-2022 transfer fee is withheld from what the escrow and the treasury receive
+            escrow_transfer_fee_ld = OtcConfig::get_transfer_fee(
+                ctx.accounts.src_token_mint.as_ref(),
+                src_amount_ld - maker_fee_ld
+            )?;
+            treasury_transfer_fee_ld = OtcConfig::get_transfer_fee(
+                ctx.accounts.src_token_mint.as_ref(),
+                maker_fee_ld
+            )?;
+            escrowed_amount_ld = src_amount_ld - maker_fee_ld - escrow_transfer_fee_ld;
+            escrowed_amount_sd = OtcConfig::ld2sd(escrowed_amount_ld, local_decimals)?;
         }
 
         // validate pricing
-        require!(src_amount_sd != 0 && params.exchange_rate_sd != 0, OtcError::InvalidPricing);
+        require!(
+            escrowed_amount_sd != 0 && params.exchange_rate_sd != 0,
+            OtcError::InvalidPricing
+        );
+
+        // validate min fill
+        require!(params.min_fill_sd <= escrowed_amount_sd, OtcError::InvalidMinFill);
+
+        // validate allowed buyer, the zero address means any buyer on the wire
+        require!(
+            params.allowed_buyer != Some(<[u8; 32]>::default()),
+            OtcError::InvalidAllowedBuyer
+        );
+
+        // validate expiry
+        if let Some(expires_at) = params.expires_at {
+            require!(expires_at > Clock::get()?.unix_timestamp, OtcError::InvalidExpiry);
+        }
 
         let offer_id = Offer::hash_offer(
             src_seller_address,
-            OtcConfig::EID,
+            ctx.accounts.otc_config.eid,
             params.dst_eid,
             &src_token_address,
             &params.dst_token_address,
-            params.exchange_rate_sd
+            params.exchange_rate_sd,
+            params.nonce
         );
         let messaging_fee: MessagingFee;
 
-        if params.dst_eid != OtcConfig::EID {
+        if params.dst_eid != ctx.accounts.otc_config.eid {
             // crosschain
             let peer = ctx.accounts.peer.as_ref().expect(OtcConfig::ERROR_MSG);
             let enforced_options = ctx.accounts.enforced_options
@@ -81,13 +121,28 @@ impl QuoteCreateOffer<'_> {
                 &(Offer {
                     src_seller_address: *src_seller_address,
                     dst_seller_address: params.dst_seller_address,
-                    src_eid: OtcConfig::EID,
+                    src_eid: ctx.accounts.otc_config.eid,
                     dst_eid: params.dst_eid,
                     src_token_address,
                     dst_token_address: params.dst_token_address,
-                    src_amount_sd,
+                    src_amount_sd: escrowed_amount_sd,
                     exchange_rate_sd: params.exchange_rate_sd,
+                    expires_at: params.expires_at,
+                    nonce: params.nonce,
+                    min_fill_sd: params.min_fill_sd,
+                    all_or_nothing: params.all_or_nothing,
+                    allowed_buyer: params.allowed_buyer,
+                    buyers_root: params.buyers_root,
+                    src_token_program: OtcConfig::get_token_program_address(
+                        ctx.accounts.src_token_mint.as_ref()
+                    ),
+                    src_granularity_sd: OtcConfig::get_granularity_sd(
+                        OtcConfig::get_local_decimals(ctx.accounts.src_token_mint.as_ref())
+                    ),
+                    cancel_pending: false,
+                    price_revision: 0,
 
+                    payer: Pubkey::new_from_array(*src_seller_address),
                     bump: u8::default(), // unused (required for Offer struct creation)
                 })
             );
@@ -112,6 +167,10 @@ impl QuoteCreateOffer<'_> {
             CreateOfferReceipt {
                 offer_id,
                 src_amount_ld,
+                maker_fee_ld,
+                maker_fee_bps,
+                escrowed_amount_ld,
+                transfer_fee_ld: escrow_transfer_fee_ld + treasury_transfer_fee_ld,
             },
             messaging_fee,
         ))
@@ -0,0 +1,130 @@
+use crate::*;
+use anchor_spl::token_interface::{ Mint, TokenInterface };
+use oapp::endpoint::{ instructions::QuoteParams as EndpointQuoteParams, MessagingFee };
+
+#[derive(Accounts)]
+#[instruction(params: TopUpOfferParams, pay_in_lz_token: bool)]
+pub struct QuoteTopUpOffer<'info> {
+    #[account(seeds = [OtcConfig::OTC_SEED], bump = otc_config.bump)]
+    pub otc_config: Account<'info, OtcConfig>,
+
+    #[account(
+        seeds = [&params.offer_id],
+        bump = offer.bump,
+        constraint = offer.src_eid == otc_config.eid @ OtcError::InvalidEid,
+        constraint = !offer.cancel_pending @ OtcError::CancelPending
+    )]
+    pub offer: Account<'info, Offer>,
+
+    #[account(
+        mint::token_program = token_program,
+        constraint = src_token_mint.key() == Pubkey::new_from_array(offer.src_token_address) @ OtcError::InvalidSrcTokenMint
+    )]
+    /// NOTICE: required for src spl offer
+    pub src_token_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        seeds = [Peer::PEER_SEED, otc_config.key().as_ref(), &offer.dst_eid.to_be_bytes()],
+        bump = peer.bump
+    )]
+    /// NOTICE: required for crosschain offer
+    pub peer: Option<Account<'info, Peer>>,
+
+    #[account(
+        seeds = [
+            EnforcedOptions::ENFORCED_OPTIONS_SEED,
+            otc_config.key().as_ref(),
+            &offer.dst_eid.to_be_bytes(),
+        ],
+        bump = enforced_options.bump
+    )]
+    /// NOTICE: required for crosschain offer
+    pub enforced_options: Option<Account<'info, EnforcedOptions>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+}
+
+impl QuoteTopUpOffer<'_> {
+    pub fn apply(
+        ctx: &mut Context<QuoteTopUpOffer>,
+        params: &TopUpOfferParams,
+        pay_in_lz_token: bool
+    ) -> Result<(TopUpOfferReceipt, MessagingFee)> {
+        require!(
+            !ctx.accounts.offer.is_expired(Clock::get()?.unix_timestamp),
+            OtcError::OfferExpired
+        );
+
+        let maker_fee_bps = ctx.accounts.otc_config.maker_fee_bps;
+
+        let (src_amount_sd, src_amount_ld, maker_fee_sd, maker_fee_ld): (u64, u64, u64, u64);
+        let (escrowed_amount_sd, escrowed_amount_ld): (u64, u64);
+        let (escrow_transfer_fee_ld, treasury_transfer_fee_ld): (u64, u64);
+        {
+            let local_decimals = OtcConfig::get_local_decimals(
+                ctx.accounts.src_token_mint.as_ref()
+            );
+            (src_amount_sd, src_amount_ld) = OtcConfig::remove_dust(
+                params.src_amount_ld,
+                local_decimals
+            )?;
+            maker_fee_sd = OtcConfig::to_maker_fee(src_amount_sd, maker_fee_bps, local_decimals);
+            maker_fee_ld = OtcConfig::sd2ld(maker_fee_sd, local_decimals);
+
+            // token-2022 transfer fee is withheld from what the escrow and the treasury receive
+            escrow_transfer_fee_ld = OtcConfig::get_transfer_fee(
+                ctx.accounts.src_token_mint.as_ref(),
+                src_amount_ld - maker_fee_ld
+            )?;
+            treasury_transfer_fee_ld = OtcConfig::get_transfer_fee(
+                ctx.accounts.src_token_mint.as_ref(),
+                maker_fee_ld
+            )?;
+            escrowed_amount_ld = src_amount_ld - maker_fee_ld - escrow_transfer_fee_ld;
+            escrowed_amount_sd = OtcConfig::ld2sd(escrowed_amount_ld, local_decimals)?;
+        }
+
+        // validate amount
+        require!(escrowed_amount_sd != 0, OtcError::InvalidAmount);
+
+        let messaging_fee = if ctx.accounts.offer.dst_eid != ctx.accounts.otc_config.eid {
+            // crosschain
+            let peer = ctx.accounts.peer.as_ref().expect(OtcConfig::ERROR_MSG);
+            let enforced_options = ctx.accounts.enforced_options
+                .as_ref()
+                .expect(OtcConfig::ERROR_MSG);
+
+            let payload = build_top_up_offer_payload(
+                &params.offer_id,
+                &ctx.accounts.offer,
+                escrowed_amount_sd
+            );
+            oapp::endpoint_cpi::quote(
+                ctx.accounts.otc_config.endpoint_program,
+                ctx.remaining_accounts,
+                EndpointQuoteParams {
+                    sender: ctx.accounts.otc_config.key(),
+                    dst_eid: ctx.accounts.offer.dst_eid,
+                    receiver: peer.address,
+                    message: payload,
+                    pay_in_lz_token,
+                    options: enforced_options.get_enforced_options(&None),
+                }
+            )?
+        } else {
+            // monochain
+            MessagingFee::default()
+        };
+
+        Ok((
+            TopUpOfferReceipt {
+                src_amount_ld,
+                maker_fee_ld,
+                maker_fee_bps,
+                escrowed_amount_ld,
+                transfer_fee_ld: escrow_transfer_fee_ld + treasury_transfer_fee_ld,
+            },
+            messaging_fee,
+        ))
+    }
+}
@@ -0,0 +1,81 @@
+use crate::*;
+use oapp::endpoint::{ instructions::QuoteParams as EndpointQuoteParams, MessagingFee };
+
+#[derive(Accounts)]
+#[instruction(params: UpdateOfferPriceParams, pay_in_lz_token: bool)]
+pub struct QuoteUpdateOfferPrice<'info> {
+    #[account(seeds = [OtcConfig::OTC_SEED], bump = otc_config.bump)]
+    pub otc_config: Account<'info, OtcConfig>,
+
+    #[account(
+        seeds = [&params.offer_id],
+        bump = offer.bump,
+        constraint = offer.src_eid == otc_config.eid @ OtcError::InvalidEid
+    )]
+    pub offer: Account<'info, Offer>,
+
+    #[account(
+        seeds = [Peer::PEER_SEED, otc_config.key().as_ref(), &offer.dst_eid.to_be_bytes()],
+        bump = peer.bump
+    )]
+    /// NOTICE: required for crosschain offer
+    pub peer: Option<Account<'info, Peer>>,
+
+    #[account(
+        seeds = [
+            EnforcedOptions::ENFORCED_OPTIONS_SEED,
+            otc_config.key().as_ref(),
+            &offer.dst_eid.to_be_bytes(),
+        ],
+        bump = enforced_options.bump
+    )]
+    /// NOTICE: required for crosschain offer
+    pub enforced_options: Option<Account<'info, EnforcedOptions>>,
+}
+
+impl QuoteUpdateOfferPrice<'_> {
+    pub fn apply(
+        ctx: &mut Context<QuoteUpdateOfferPrice>,
+        params: &UpdateOfferPriceParams,
+        pay_in_lz_token: bool
+    ) -> Result<MessagingFee> {
+        require!(
+            !ctx.accounts.offer.is_expired(Clock::get()?.unix_timestamp),
+            OtcError::OfferExpired
+        );
+
+        // validate pricing
+        require!(params.exchange_rate_sd != 0, OtcError::InvalidPricing);
+
+        let messaging_fee = if ctx.accounts.offer.dst_eid != ctx.accounts.otc_config.eid {
+            // crosschain
+            let peer = ctx.accounts.peer.as_ref().expect(OtcConfig::ERROR_MSG);
+            let enforced_options = ctx.accounts.enforced_options
+                .as_ref()
+                .expect(OtcConfig::ERROR_MSG);
+
+            let payload = build_offer_repriced_payload(
+                &params.offer_id,
+                params.exchange_rate_sd,
+                ctx.accounts.offer.price_revision.saturating_add(1)
+            );
+            oapp::endpoint_cpi::quote(
+                ctx.accounts.otc_config.endpoint_program,
+                ctx.remaining_accounts,
+                EndpointQuoteParams {
+                    sender: ctx.accounts.otc_config.key(),
+                    dst_eid: ctx.accounts.offer.dst_eid,
+                    receiver: peer.address,
+                    message: payload,
+                    pay_in_lz_token,
+                    options: enforced_options.get_enforced_options(&None),
+                }
+            )?
+        } else {
+            // monochain
+            MessagingFee::default()
+        };
+
+        Ok(messaging_fee)
+    }
+}
@@ -1,23 +1,36 @@
 use crate::*;
 
 use oapp::endpoint_cpi::LzAccount;
-use anchor_spl::{
-    associated_token::{
-        get_associated_token_address_with_program_id,
-        ID as associated_token_program,
-    },
-    token::ID as token_program,
+use anchor_spl::associated_token::{
+    get_associated_token_address_with_program_id,
+    ID as associated_token_program,
 };
 
 pub fn receive_offer_accepted_types(
     ctx: &Context<LzReceiveTypes>,
     message: &[u8]
 ) -> Vec<LzAccount> {
-    let (offer_id, _, src_buyer_address, _, src_token_address) = decode_offer_accepted(message);
+    let (
+        offer_id,
+        _,
+        src_buyer_address,
+        _,
+        src_token_address,
+        src_seller_address,
+        src_token_program_address,
+    ) = decode_offer_accepted(message);
 
     let (offer, _) = Pubkey::find_program_address(&[&offer_id], ctx.program_id);
-    let (escrow, _) = Pubkey::find_program_address(&[Escrow::ESCROW_SEED], ctx.program_id);
+    let (escrow, _) = Pubkey::find_program_address(
+        &[Escrow::ESCROW_SEED, &offer_id],
+        ctx.program_id
+    );
+    let (escrow_ledger, _) = Pubkey::find_program_address(
+        &[EscrowLedger::ESCROW_LEDGER_SEED, &src_token_address],
+        ctx.program_id
+    );
     let src_buyer = Pubkey::new_from_array(src_buyer_address);
+    let src_seller = Pubkey::new_from_array(src_seller_address);
     let null_account = LzAccount {
         pubkey: *ctx.program_id,
         is_signer: false,
@@ -32,6 +45,12 @@ pub fn receive_offer_accepted_types(
                 is_signer: false,
                 is_writable: true,
             },
+            LzAccount {
+                pubkey: src_seller,
+                is_signer: false,
+                is_writable: true,
+            }, // offer_payer - src seller pays for the offer on the src chain
+            null_account.clone(), // NO src_seller_ata
             null_account.clone(), // NO enforced_options
             LzAccount {
                 pubkey: src_buyer,
@@ -44,23 +63,34 @@ pub fn receive_offer_accepted_types(
                 is_signer: false,
                 is_writable: true,
             }, // escrow
+            LzAccount {
+                pubkey: escrow_ledger,
+                is_signer: false,
+                is_writable: true,
+            }, // escrow_ledger
             null_account.clone(), // NO src_escrow_ata
             null_account.clone(), // NO src_token_mint
             null_account.clone(), // NO associated_token_program
             null_account.clone() // NO token_program
         ]
     } else {
-        // src token is SPL
+        // src token is SPL or Token-2022
         let src_token_mint = Pubkey::new_from_array(src_token_address);
+        let token_program = Pubkey::new_from_array(src_token_program_address);
         let src_buyer_ata = get_associated_token_address_with_program_id(
             &src_buyer,
             &src_token_mint,
-            &token_program // stick to spl token program for mvp
+            &token_program
+        );
+        let src_seller_ata = get_associated_token_address_with_program_id(
+            &src_seller,
+            &src_token_mint,
+            &token_program
         );
         let src_escrow_ata = get_associated_token_address_with_program_id(
             &escrow,
             &src_token_mint,
-            &token_program // stick to spl token program for mvp
+            &token_program
         );
 
         vec![
@@ -69,6 +99,16 @@ pub fn receive_offer_accepted_types(
                 is_signer: false,
                 is_writable: true,
             },
+            LzAccount {
+                pubkey: src_seller,
+                is_signer: false,
+                is_writable: true,
+            }, // offer_payer - src seller pays for the offer on the src chain
+            LzAccount {
+                pubkey: src_seller_ata,
+                is_signer: false,
+                is_writable: true,
+            }, // src_seller_ata
             null_account.clone(), // NO enforced_options
             LzAccount {
                 pubkey: src_buyer,
@@ -85,6 +125,11 @@ pub fn receive_offer_accepted_types(
                 is_signer: false,
                 is_writable: true,
             }, // escrow
+            LzAccount {
+                pubkey: escrow_ledger,
+                is_signer: false,
+                is_writable: true,
+            }, // escrow_ledger
             LzAccount {
                 pubkey: src_escrow_ata,
                 is_signer: false,
@@ -93,8 +138,8 @@ pub fn receive_offer_accepted_types(
             LzAccount {
                 pubkey: src_token_mint,
                 is_signer: false,
-                is_writable: false,
-            }, // src_token_mint
+                is_writable: true,
+            }, // src_token_mint - token-2022 withheld fees are harvested to it on escrow close
             LzAccount {
                 pubkey: associated_token_program,
                 is_signer: false,
@@ -110,13 +155,17 @@ pub fn receive_offer_accepted_types(
 }
 
 pub fn receive_offer_accepted(ctx: &mut Context<LzReceive>, message: &Vec<u8>) -> Result<()> {
-    let (offer_id, src_amount_sd, src_buyer_address, dst_buyer_address, _) =
+    let (offer_id, src_amount_sd, src_buyer_address, dst_buyer_address, _, _, _) =
         decode_offer_accepted(message);
 
+    ctx.accounts.assert_src_actor(&src_buyer_address, OtcError::InvalidSrcBuyer)?;
+
     let offer = &mut ctx.accounts.offer;
 
     // update state
-    offer.src_amount_sd -= src_amount_sd;
+    offer.src_amount_sd = offer.src_amount_sd
+        .checked_sub(src_amount_sd)
+        .ok_or(OtcError::ExcessiveAmount)?;
 
     // emit event
     emit_cpi!(OfferAccepted {
@@ -124,19 +173,23 @@ pub fn receive_offer_accepted(ctx: &mut Context<LzReceive>, message: &Vec<u8>) -
         src_amount_sd,
         src_buyer_address,
         dst_buyer_address,
+        referrer: None, // referrers are paid on the dst chain
     });
 
     // transfer src tokens
+    // NOTICE: no fee here, maker fee was taken on create and taker fee on accept (dst chain)
     {
         let src_token_mint = ctx.accounts.src_token_mint.as_deref();
         let escrow = ctx.accounts.escrow.as_deref().expect(OtcConfig::ERROR_MSG);
 
         let src_amount_ld: u64;
         {
-            let decimal_conversion_rate = OtcConfig::get_decimal_conversion_rate(src_token_mint);
-            src_amount_ld = OtcConfig::sd2ld(src_amount_sd, decimal_conversion_rate);
+            let local_decimals = OtcConfig::get_local_decimals(src_token_mint);
+            src_amount_ld = OtcConfig::sd2ld(src_amount_sd, local_decimals);
         }
 
+        ctx.accounts.escrow_ledger.as_mut().expect(OtcConfig::ERROR_MSG).release(src_amount_ld)?;
+
         OtcConfig::transfer(
             escrow.to_account_info().as_ref(),
             src_amount_ld,
@@ -145,8 +198,31 @@ pub fn receive_offer_accepted(ctx: &mut Context<LzReceive>, message: &Vec<u8>) -
             ctx.accounts.src_escrow_ata.as_deref(),
             src_token_mint,
             ctx.accounts.src_actor_ata.as_deref(),
-            Some(&[&[Escrow::ESCROW_SEED, &[escrow.bump]]])
+            Some(&[&[Escrow::ESCROW_SEED, &offer_id, &[escrow.bump]]])
+        )?;
+    }
+
+    // close fully filled offer
+    if ctx.accounts.offer.src_amount_sd == 0 {
+        // emit event
+        emit_cpi!(OfferFilled {
+            offer_id,
+        });
+
+        let offer_payer = ctx.accounts.offer_payer.as_ref().expect(OtcConfig::ERROR_MSG);
+
+        // delete offer and its escrow vault, rounding dust goes back to the seller
+        Escrow::close_vault(
+            ctx.accounts.escrow.as_deref().expect(OtcConfig::ERROR_MSG),
+            &offer_id,
+            ctx.accounts.escrow_ledger.as_mut().expect(OtcConfig::ERROR_MSG),
+            offer_payer,
+            ctx.accounts.token_program.as_ref(),
+            ctx.accounts.src_escrow_ata.as_deref(),
+            ctx.accounts.src_token_mint.as_deref(),
+            ctx.accounts.src_seller_ata.as_deref()
         )?;
+        close(ctx.accounts.offer.to_account_info(), offer_payer.to_account_info())?;
     }
 
     Ok(())
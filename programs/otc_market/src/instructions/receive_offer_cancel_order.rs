@@ -35,6 +35,12 @@ pub fn receive_offer_cancel_order_types(
             is_signer: false,
             is_writable: true,
         },
+        LzAccount {
+            pubkey: Pubkey::default(),
+            is_signer: true,
+            is_writable: true,
+        }, // offer_payer - the executor that delivered the offer created message paid for the copy
+        null_account.clone(), // NO src_seller_ata
         LzAccount {
             pubkey: enforced_options,
             is_signer: false,
@@ -43,6 +49,7 @@ pub fn receive_offer_cancel_order_types(
         null_account.clone(), // NO src_actor
         null_account.clone(), // NO src_actor_ata
         null_account.clone(), // NO escrow
+        null_account.clone(), // NO escrow_ledger
         null_account.clone(), // NO src_escrow_ata
         null_account.clone(), // NO src_token_mint
         null_account.clone(), // NO associated_token_program
@@ -52,10 +59,16 @@ pub fn receive_offer_cancel_order_types(
 
 pub fn receive_offer_cancel_order(ctx: &mut Context<LzReceive>, message: &Vec<u8>) -> Result<()> {
     let enforced_options = ctx.accounts.enforced_options.as_ref().expect(OtcConfig::ERROR_MSG);
+    let offer_payer = ctx.accounts.offer_payer.as_ref().expect(OtcConfig::ERROR_MSG);
 
     let offer_id = decode_offer_cancel_order(message);
 
-    let payload = build_cancel_offer_payload(&offer_id);
+    let payload = build_cancel_offer_payload(
+        &offer_id,
+        &ctx.accounts.offer.src_seller_address,
+        &ctx.accounts.offer.src_token_address,
+        &ctx.accounts.offer.src_token_program
+    );
 
     let fee = oapp::endpoint_cpi::quote(
         ctx.accounts.otc_config.endpoint_program,
@@ -85,5 +98,13 @@ pub fn receive_offer_cancel_order(ctx: &mut Context<LzReceive>, message: &Vec<u8
         }
     )?;
 
+    // emit event
+    emit_cpi!(OfferCanceled {
+        offer_id,
+    });
+
+    // delete the offer copy, the offer can't be accepted on this chain anymore
+    close(ctx.accounts.offer.to_account_info(), offer_payer.to_account_info())?;
+
     Ok(())
 }
@@ -1,12 +1,9 @@
 use crate::*;
 
 use oapp::endpoint_cpi::LzAccount;
-use anchor_spl::{
-    associated_token::{
-        get_associated_token_address_with_program_id,
-        ID as associated_token_program,
-    },
-    token::ID as token_program,
+use anchor_spl::associated_token::{
+    get_associated_token_address_with_program_id,
+    ID as associated_token_program,
 };
 
 // receive offer canceled
@@ -15,11 +12,19 @@ pub fn receive_offer_canceled_types(
     ctx: &Context<LzReceiveTypes>,
     message: &[u8]
 ) -> Vec<LzAccount> {
-    let (offer_id, src_seller_address, src_token_address) = decode_offer_canceled(message);
+    let (offer_id, src_seller_address, src_token_address, src_token_program_address) =
+        decode_offer_canceled(message);
 
     let (offer, _) = Pubkey::find_program_address(&[&offer_id], ctx.program_id);
     let src_seller = Pubkey::new_from_array(src_seller_address);
-    let (escrow, _) = Pubkey::find_program_address(&[Escrow::ESCROW_SEED], ctx.program_id);
+    let (escrow, _) = Pubkey::find_program_address(
+        &[Escrow::ESCROW_SEED, &offer_id],
+        ctx.program_id
+    );
+    let (escrow_ledger, _) = Pubkey::find_program_address(
+        &[EscrowLedger::ESCROW_LEDGER_SEED, &src_token_address],
+        ctx.program_id
+    );
     let null_account = LzAccount {
         pubkey: *ctx.program_id,
         is_signer: false,
@@ -34,6 +39,8 @@ pub fn receive_offer_canceled_types(
                 is_signer: false,
                 is_writable: true,
             },
+            null_account.clone(), // NO offer_payer
+            null_account.clone(), // NO src_seller_ata
             null_account.clone(), // NO enforced_options
             LzAccount {
                 pubkey: src_seller,
@@ -46,24 +53,30 @@ pub fn receive_offer_canceled_types(
                 is_signer: false,
                 is_writable: true,
             }, // escrow
+            LzAccount {
+                pubkey: escrow_ledger,
+                is_signer: false,
+                is_writable: true,
+            }, // escrow_ledger
             null_account.clone(), // NO src_escrow_ata
             null_account.clone(), // NO src_token_mint
             null_account.clone(), // NO associated_token_program
             null_account.clone() // NO token_program
         ]
     } else {
-        // src token is SPL
+        // src token is SPL or Token-2022
         let src_token_mint = Pubkey::new_from_array(src_token_address);
+        let token_program = Pubkey::new_from_array(src_token_program_address);
 
         let src_seller_ata = get_associated_token_address_with_program_id(
             &src_seller,
             &src_token_mint,
-            &token_program // stick to spl token program for mvp
+            &token_program
         );
         let src_escrow_ata = get_associated_token_address_with_program_id(
             &escrow,
             &src_token_mint,
-            &token_program // stick to spl token program for mvp
+            &token_program
         );
 
         vec![
@@ -72,6 +85,8 @@ pub fn receive_offer_canceled_types(
                 is_signer: false,
                 is_writable: true,
             },
+            null_account.clone(), // NO offer_payer
+            null_account.clone(), // NO src_seller_ata
             null_account.clone(), // NO enforced_options
             LzAccount {
                 pubkey: src_seller,
@@ -88,6 +103,11 @@ pub fn receive_offer_canceled_types(
                 is_signer: false,
                 is_writable: true,
             }, // escrow
+            LzAccount {
+                pubkey: escrow_ledger,
+                is_signer: false,
+                is_writable: true,
+            }, // escrow_ledger
             LzAccount {
                 pubkey: src_escrow_ata,
                 is_signer: false,
@@ -96,8 +116,8 @@ pub fn receive_offer_canceled_types(
             LzAccount {
                 pubkey: src_token_mint,
                 is_signer: false,
-                is_writable: false,
-            }, // src_token_mint
+                is_writable: true,
+            }, // src_token_mint - token-2022 withheld fees are harvested to it on escrow close
             LzAccount {
                 pubkey: associated_token_program,
                 is_signer: false,
@@ -113,17 +133,25 @@ pub fn receive_offer_canceled_types(
 }
 
 pub fn receive_offer_canceled(ctx: &mut Context<LzReceive>, message: &Vec<u8>) -> Result<()> {
-    let (offer_id, _, _) = decode_offer_canceled(message);
+    let (offer_id, _, _, _) = decode_offer_canceled(message);
+
+    ctx.accounts.assert_src_actor(
+        &ctx.accounts.offer.src_seller_address,
+        OtcError::InvalidSrcSeller
+    )?;
 
     let escrow = ctx.accounts.escrow.as_deref().expect(OtcConfig::ERROR_MSG);
     let src_token_mint = ctx.accounts.src_token_mint.as_deref();
 
     let amount_ld: u64;
     {
-        let decimal_conversion_rate = OtcConfig::get_decimal_conversion_rate(src_token_mint);
-        amount_ld = OtcConfig::sd2ld(ctx.accounts.offer.src_amount_sd, decimal_conversion_rate);
+        let local_decimals = OtcConfig::get_local_decimals(src_token_mint);
+        amount_ld = OtcConfig::sd2ld(ctx.accounts.offer.src_amount_sd, local_decimals);
     }
 
+    // update state
+    ctx.accounts.escrow_ledger.as_mut().expect(OtcConfig::ERROR_MSG).release(amount_ld)?;
+
     // send src tokens to the seller
     OtcConfig::transfer(
         escrow.as_ref(),
@@ -133,7 +161,7 @@ pub fn receive_offer_canceled(ctx: &mut Context<LzReceive>, message: &Vec<u8>) -
         ctx.accounts.src_escrow_ata.as_deref(),
         src_token_mint,
         ctx.accounts.src_actor_ata.as_deref(),
-        Some(&[&[Escrow::ESCROW_SEED, &[escrow.bump]]])
+        Some(&[&[Escrow::ESCROW_SEED, &offer_id, &[escrow.bump]]])
     )?;
 
     // emit event
@@ -141,11 +169,20 @@ pub fn receive_offer_canceled(ctx: &mut Context<LzReceive>, message: &Vec<u8>) -
         offer_id,
     });
 
-    // delete offer
-    close(
-        ctx.accounts.offer.to_account_info(),
-        ctx.accounts.src_actor.as_ref().expect(OtcConfig::ERROR_MSG).to_account_info()
+    let src_seller = ctx.accounts.src_actor.as_ref().expect(OtcConfig::ERROR_MSG);
+
+    // delete offer and its escrow vault
+    Escrow::close_vault(
+        escrow,
+        &offer_id,
+        ctx.accounts.escrow_ledger.as_mut().expect(OtcConfig::ERROR_MSG),
+        src_seller,
+        ctx.accounts.token_program.as_ref(),
+        ctx.accounts.src_escrow_ata.as_deref(),
+        src_token_mint,
+        ctx.accounts.src_actor_ata.as_deref()
     )?;
+    close(ctx.accounts.offer.to_account_info(), src_seller.to_account_info())?;
 
     Ok(())
 }
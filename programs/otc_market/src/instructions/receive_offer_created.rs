@@ -19,10 +19,13 @@ pub fn receive_offer_created_types(
             is_signer: false,
             is_writable: true,
         },
+        null_account.clone(), // NO offer_payer
+        null_account.clone(), // NO src_seller_ata
         null_account.clone(), // NO enforced_options
         null_account.clone(), // NO src_actor
         null_account.clone(), // NO src_actor_ata
         null_account.clone(), // NO escrow
+        null_account.clone(), // NO escrow_ledger
         null_account.clone(), // NO src_escrow_ata
         null_account.clone(), // NO src_token_mint
         null_account.clone(), // NO associated_token_program
@@ -31,7 +34,11 @@ pub fn receive_offer_created_types(
 }
 
 pub fn receive_offer_created(ctx: &mut Context<LzReceive>, message: &Vec<u8>) -> Result<()> {
-    let offer: Offer = decode_offer_created(message, ctx.bumps.offer);
+    let offer: Offer = decode_offer_created(
+        message,
+        ctx.accounts.payer.key(),
+        ctx.bumps.offer
+    );
 
     // store, hash offer
     let offer_id = ctx.accounts.offer.init(&offer);
@@ -47,6 +54,12 @@ pub fn receive_offer_created(ctx: &mut Context<LzReceive>, message: &Vec<u8>) ->
         dst_token_address: offer.dst_token_address,
         src_amount_sd: offer.src_amount_sd,
         exchange_rate_sd: offer.exchange_rate_sd,
+        expires_at: offer.expires_at,
+        nonce: offer.nonce,
+        min_fill_sd: offer.min_fill_sd,
+        all_or_nothing: offer.all_or_nothing,
+        allowed_buyer: offer.allowed_buyer,
+        buyers_root: offer.buyers_root,
     });
 
     Ok(())
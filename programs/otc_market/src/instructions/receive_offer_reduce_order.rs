@@ -0,0 +1,79 @@
+use crate::*;
+
+use oapp::endpoint_cpi::LzAccount;
+use oapp::endpoint::{
+    instructions::QuoteParams as EndpointQuoteParams,
+    instructions::SendParams as EndpointSendParams,
+};
+
+pub fn receive_offer_reduce_order_types(
+    ctx: &Context<LzReceiveTypes>,
+    message: &[u8],
+    src_eid: u32
+) -> Vec<LzAccount> {
+    // same accounts as offer cancel order - the reply is sent back to the src chain and an emptied copy
+    // is closed to its offer_payer
+    receive_offer_cancel_order_types(ctx, message, src_eid)
+}
+
+pub fn receive_offer_reduce_order(ctx: &mut Context<LzReceive>, message: &[u8]) -> Result<()> {
+    let (offer_id, src_amount_sd) = decode_offer_reduce_order(message);
+
+    let enforced_options = ctx.accounts.enforced_options.as_ref().expect(OtcConfig::ERROR_MSG);
+    let offer_payer = ctx.accounts.offer_payer.as_ref().expect(OtcConfig::ERROR_MSG);
+
+    // the copy may have been partially filled meanwhile - only what is left can be withdrawn
+    let src_amount_sd = src_amount_sd.min(ctx.accounts.offer.src_amount_sd);
+
+    // update state
+    ctx.accounts.offer.src_amount_sd -= src_amount_sd;
+
+    // emit event
+    emit_cpi!(OfferReduced {
+        offer_id,
+        src_amount_sd,
+    });
+
+    let payload = build_offer_reduced_payload(
+        &offer_id,
+        &ctx.accounts.offer.src_seller_address,
+        &ctx.accounts.offer.src_token_address,
+        &ctx.accounts.offer.src_token_program,
+        src_amount_sd
+    );
+
+    let fee = oapp::endpoint_cpi::quote(
+        ctx.accounts.otc_config.endpoint_program,
+        ctx.remaining_accounts,
+        EndpointQuoteParams {
+            sender: ctx.accounts.otc_config.key(),
+            dst_eid: ctx.accounts.offer.src_eid,
+            receiver: ctx.accounts.peer.address,
+            message: payload.clone(),
+            pay_in_lz_token: false,
+            options: enforced_options.get_enforced_options(&None),
+        }
+    )?;
+
+    oapp::endpoint_cpi::send(
+        ctx.accounts.otc_config.endpoint_program,
+        ctx.accounts.otc_config.key(),
+        ctx.remaining_accounts,
+        &[OtcConfig::OTC_SEED, &[ctx.accounts.otc_config.bump]],
+        EndpointSendParams {
+            dst_eid: ctx.accounts.offer.src_eid,
+            receiver: ctx.accounts.peer.address,
+            message: payload,
+            options: enforced_options.get_enforced_options(&None),
+            native_fee: fee.native_fee,
+            lz_token_fee: fee.lz_token_fee,
+        }
+    )?;
+
+    if ctx.accounts.offer.src_amount_sd == 0 {
+        // delete the offer copy, nothing left to accept on this chain
+        close(ctx.accounts.offer.to_account_info(), offer_payer.to_account_info())?;
+    }
+
+    Ok(())
+}
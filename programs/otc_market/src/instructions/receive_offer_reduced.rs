@@ -0,0 +1,65 @@
+use crate::*;
+
+use oapp::endpoint_cpi::LzAccount;
+
+pub fn receive_offer_reduced_types(ctx: &Context<LzReceiveTypes>, message: &[u8]) -> Vec<LzAccount> {
+    // same accounts as offer canceled - src tokens are returned to the seller
+    receive_offer_canceled_types(ctx, message)
+}
+
+pub fn receive_offer_reduced(ctx: &mut Context<LzReceive>, message: &[u8]) -> Result<()> {
+    let ((offer_id, _, _, _), src_amount_sd) = decode_offer_reduced(message);
+
+    ctx.accounts.assert_src_actor(
+        &ctx.accounts.offer.src_seller_address,
+        OtcError::InvalidSrcSeller
+    )?;
+
+    let escrow = ctx.accounts.escrow.as_deref().expect(OtcConfig::ERROR_MSG);
+    let src_token_mint = ctx.accounts.src_token_mint.as_deref();
+
+    let amount_ld = OtcConfig::sd2ld(src_amount_sd, OtcConfig::get_local_decimals(src_token_mint));
+
+    // update state
+    ctx.accounts.offer.src_amount_sd = ctx.accounts.offer.src_amount_sd
+        .checked_sub(src_amount_sd)
+        .ok_or(OtcError::ExcessiveAmount)?;
+    ctx.accounts.escrow_ledger.as_mut().expect(OtcConfig::ERROR_MSG).release(amount_ld)?;
+
+    // send src tokens to the seller
+    OtcConfig::transfer(
+        escrow.as_ref(),
+        amount_ld,
+        ctx.accounts.src_actor.as_ref(),
+        ctx.accounts.token_program.as_ref(),
+        ctx.accounts.src_escrow_ata.as_deref(),
+        src_token_mint,
+        ctx.accounts.src_actor_ata.as_deref(),
+        Some(&[&[Escrow::ESCROW_SEED, &offer_id, &[escrow.bump]]])
+    )?;
+
+    // emit event
+    emit_cpi!(OfferReduced {
+        offer_id,
+        src_amount_sd,
+    });
+
+    if ctx.accounts.offer.src_amount_sd == 0 {
+        // the remainder was accepted on the dst chain meanwhile
+        let src_seller = ctx.accounts.src_actor.as_ref().expect(OtcConfig::ERROR_MSG);
+
+        Escrow::close_vault(
+            escrow,
+            &offer_id,
+            ctx.accounts.escrow_ledger.as_mut().expect(OtcConfig::ERROR_MSG),
+            src_seller,
+            ctx.accounts.token_program.as_ref(),
+            ctx.accounts.src_escrow_ata.as_deref(),
+            src_token_mint,
+            ctx.accounts.src_actor_ata.as_deref()
+        )?;
+        close(ctx.accounts.offer.to_account_info(), src_seller.to_account_info())?;
+    }
+
+    Ok(())
+}
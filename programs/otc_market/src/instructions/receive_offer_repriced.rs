@@ -0,0 +1,32 @@
+use crate::*;
+
+use oapp::endpoint_cpi::LzAccount;
+
+pub fn receive_offer_repriced_types(
+    ctx: &Context<LzReceiveTypes>,
+    message: &[u8]
+) -> Vec<LzAccount> {
+    // same accounts as offer created - only the offer copy is touched
+    receive_offer_created_types(ctx, message)
+}
+
+pub fn receive_offer_repriced(ctx: &mut Context<LzReceive>, message: &[u8]) -> Result<()> {
+    let (offer_id, exchange_rate_sd, price_revision) = decode_offer_repriced(message);
+
+    // messages are unordered - a reprice overtaken by a newer one is dropped
+    if price_revision <= ctx.accounts.offer.price_revision {
+        return Ok(());
+    }
+
+    // update state
+    ctx.accounts.offer.exchange_rate_sd = exchange_rate_sd;
+    ctx.accounts.offer.price_revision = price_revision;
+
+    // emit event
+    emit_cpi!(OfferRepriced {
+        offer_id,
+        exchange_rate_sd,
+    });
+
+    Ok(())
+}
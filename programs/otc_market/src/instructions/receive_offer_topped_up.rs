@@ -0,0 +1,92 @@
+use crate::*;
+
+use oapp::endpoint_cpi::LzAccount;
+use oapp::endpoint::{
+    instructions::QuoteParams as EndpointQuoteParams,
+    instructions::SendParams as EndpointSendParams,
+};
+
+pub fn receive_offer_topped_up_types(
+    ctx: &Context<LzReceiveTypes>,
+    message: &[u8],
+    src_eid: u32
+) -> Vec<LzAccount> {
+    // same accounts as offer cancel order - a top up of a closed copy is bounced back to the src chain
+    receive_offer_cancel_order_types(ctx, message, src_eid)
+}
+
+pub fn receive_offer_topped_up(ctx: &mut Context<LzReceive>, message: &[u8]) -> Result<()> {
+    let top_up: Offer = decode_offer_topped_up(
+        message,
+        ctx.accounts.payer.key(),
+        ctx.bumps.offer
+    );
+
+    let offer_id = offer_id(message);
+
+    if !ctx.accounts.offer.is_initialized() {
+        // the copy was closed meanwhile - it is never rebuilt from a top up,
+        // the topped up amount is returned to the seller as if reduced
+        let enforced_options = ctx.accounts.enforced_options
+            .as_ref()
+            .expect(OtcConfig::ERROR_MSG);
+
+        let payload = build_offer_reduced_payload(
+            &offer_id,
+            &top_up.src_seller_address,
+            &top_up.src_token_address,
+            &top_up.src_token_program,
+            top_up.src_amount_sd
+        );
+
+        let fee = oapp::endpoint_cpi::quote(
+            ctx.accounts.otc_config.endpoint_program,
+            ctx.remaining_accounts,
+            EndpointQuoteParams {
+                sender: ctx.accounts.otc_config.key(),
+                dst_eid: top_up.src_eid,
+                receiver: ctx.accounts.peer.address,
+                message: payload.clone(),
+                pay_in_lz_token: false,
+                options: enforced_options.get_enforced_options(&None),
+            }
+        )?;
+
+        oapp::endpoint_cpi::send(
+            ctx.accounts.otc_config.endpoint_program,
+            ctx.accounts.otc_config.key(),
+            ctx.remaining_accounts,
+            &[OtcConfig::OTC_SEED, &[ctx.accounts.otc_config.bump]],
+            EndpointSendParams {
+                dst_eid: top_up.src_eid,
+                receiver: ctx.accounts.peer.address,
+                message: payload,
+                options: enforced_options.get_enforced_options(&None),
+                native_fee: fee.native_fee,
+                lz_token_fee: fee.lz_token_fee,
+            }
+        )?;
+
+        // emit event
+        emit_cpi!(OfferReduced {
+            offer_id,
+            src_amount_sd: top_up.src_amount_sd,
+        });
+
+        // delete the blank account init_if_needed created
+        return close(ctx.accounts.offer.to_account_info(), ctx.accounts.payer.to_account_info());
+    }
+
+    // update state
+    ctx.accounts.offer.src_amount_sd = ctx.accounts.offer.src_amount_sd
+        .checked_add(top_up.src_amount_sd)
+        .ok_or(OtcError::Overflow)?;
+
+    // emit event
+    emit_cpi!(OfferToppedUp {
+        offer_id,
+        src_amount_sd: top_up.src_amount_sd,
+    });
+
+    Ok(())
+}
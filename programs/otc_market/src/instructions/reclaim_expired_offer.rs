@@ -0,0 +1,190 @@
+use crate::*;
+use anchor_spl::token_interface::{ Mint, TokenInterface, TokenAccount };
+use oapp::endpoint::{
+    instructions::SendParams as EndpointSendParams,
+    MessagingFee,
+    MessagingReceipt,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(offer_id: [u8; 32], fee: MessagingFee, extra_options: Vec<u8>)]
+pub struct ReclaimExpiredOffer<'info> {
+    /// NOTICE: anyone can reclaim an expired offer - pays the messaging fee for crosschain offer
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = seller.key().to_bytes() == offer.src_seller_address @ OtcError::InvalidSrcSeller
+    )]
+    /// CHECK: receives escrowed src tokens and offer rent for monochain offer
+    pub seller: AccountInfo<'info>,
+
+    #[account(seeds = [OtcConfig::OTC_SEED], bump = otc_config.bump)]
+    pub otc_config: Account<'info, OtcConfig>,
+
+    #[account(
+        mut,
+        seeds = [&offer_id],
+        bump = offer.bump,
+        constraint = offer.src_eid == otc_config.eid @ OtcError::InvalidEid,
+    )]
+    pub offer: Account<'info, Offer>,
+
+    #[account(seeds = [EidPause::PAUSE_SEED, otc_config.key().as_ref(), &offer.dst_eid.to_be_bytes()], bump)]
+    /// CHECK: eid pause switch, may be uninitialized
+    pub eid_pause: AccountInfo<'info>,
+
+    /// src - NOTICE: required for monochain offer
+
+    #[account(
+        mut, // for sure created in create_offer instruction
+        associated_token::authority = seller,
+        associated_token::mint = src_token_mint,
+        associated_token::token_program = token_program
+    )]
+    /// NOTICE: required for src spl token - to_ata
+    pub src_seller_ata: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::authority = escrow,
+        associated_token::mint = src_token_mint,
+        associated_token::token_program = token_program
+    )]
+    /// NOTICE: required for src spl token - from_ata
+    pub src_escrow_ata: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut, seeds = [Escrow::ESCROW_SEED, &offer_id], bump = escrow.bump)]
+    /// NOTICE: required for src sol token - from | required for src spl token - authority
+    pub escrow: Option<Account<'info, Escrow>>,
+
+    #[account(
+        mut,
+        seeds = [EscrowLedger::ESCROW_LEDGER_SEED, offer.src_token_address.as_ref()],
+        bump = escrow_ledger.bump
+    )]
+    /// NOTICE: required for monochain offer
+    pub escrow_ledger: Option<Account<'info, EscrowLedger>>,
+
+    #[account(
+        mut,
+        mint::token_program = token_program,
+        constraint = src_token_mint.key() == Pubkey::new_from_array(offer.src_token_address) @ OtcError::InvalidSrcTokenMint,
+    )]
+    /// NOTICE: required for src spl token - token_mint
+    pub src_token_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        seeds = [Peer::PEER_SEED, otc_config.key().as_ref(), &offer.dst_eid.to_be_bytes()],
+        bump = peer.bump
+    )]
+    /// NOTICE: required for crosschain offer
+    pub peer: Option<Account<'info, Peer>>,
+
+    #[account(
+        seeds = [
+            EnforcedOptions::ENFORCED_OPTIONS_SEED,
+            otc_config.key().as_ref(),
+            &offer.dst_eid.to_be_bytes(),
+        ],
+        bump = enforced_options.bump
+    )]
+    /// NOTICE: required for crosschain offer
+    pub enforced_options: Option<Account<'info, EnforcedOptions>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+}
+
+impl ReclaimExpiredOffer<'_> {
+    pub fn apply(
+        ctx: &mut Context<ReclaimExpiredOffer>,
+        offer_id: &[u8; 32],
+        fee: &MessagingFee,
+        extra_options: &Vec<u8>
+    ) -> Result<MessagingReceipt> {
+        ctx.accounts.otc_config.assert_cancellations_not_paused(&ctx.accounts.eid_pause)?;
+
+        require!(
+            ctx.accounts.offer.is_expired(Clock::get()?.unix_timestamp),
+            OtcError::OfferNotExpired
+        );
+
+        let mut receipt = MessagingReceipt::default();
+
+        if ctx.accounts.offer.src_eid == ctx.accounts.offer.dst_eid {
+            // monochain offer
+            let escrow = ctx.accounts.escrow.as_ref().expect(OtcConfig::ERROR_MSG);
+            let src_token_mint = ctx.accounts.src_token_mint.as_ref();
+
+            let amount_ld: u64;
+            {
+                let local_decimals = OtcConfig::get_local_decimals(src_token_mint);
+                amount_ld = OtcConfig::sd2ld(ctx.accounts.offer.src_amount_sd, local_decimals);
+            }
+
+            // update state
+            ctx.accounts.escrow_ledger.as_mut().expect(OtcConfig::ERROR_MSG).release(amount_ld)?;
+
+            // send src tokens to the seller
+            OtcConfig::transfer(
+                escrow.as_ref(),
+                amount_ld,
+                Some(ctx.accounts.seller.as_ref()),
+                ctx.accounts.token_program.as_ref(),
+                ctx.accounts.src_escrow_ata.as_ref(),
+                src_token_mint,
+                ctx.accounts.src_seller_ata.as_ref(),
+                Some(&[&[Escrow::ESCROW_SEED, offer_id, &[escrow.bump]]])
+            )?;
+
+            // emit event
+            emit_cpi!(OfferCanceled {
+                offer_id: *offer_id,
+            });
+
+            // delete offer and its escrow vault
+            Escrow::close_vault(
+                escrow,
+                offer_id,
+                ctx.accounts.escrow_ledger.as_mut().expect(OtcConfig::ERROR_MSG),
+                ctx.accounts.seller.as_ref(),
+                ctx.accounts.token_program.as_ref(),
+                ctx.accounts.src_escrow_ata.as_ref(),
+                src_token_mint,
+                ctx.accounts.src_seller_ata.as_ref()
+            )?;
+            close(ctx.accounts.offer.to_account_info(), ctx.accounts.seller.to_account_info())?;
+        } else {
+            // crosschain offer - src tokens are returned once the dst chain confirms the cancel,
+            // so that an accept already in flight is still settled from the escrow
+            let peer = ctx.accounts.peer.as_ref().expect(OtcConfig::ERROR_MSG);
+            let enforced_options = ctx.accounts.enforced_options
+                .as_ref()
+                .expect(OtcConfig::ERROR_MSG);
+
+            // update state
+            ctx.accounts.offer.cancel_pending = true;
+
+            let payload = build_cancel_offer_order_payload(offer_id);
+
+            receipt = oapp::endpoint_cpi::send(
+                ctx.accounts.otc_config.endpoint_program,
+                ctx.accounts.otc_config.key(),
+                ctx.remaining_accounts,
+                &[OtcConfig::OTC_SEED, &[ctx.accounts.otc_config.bump]],
+                EndpointSendParams {
+                    dst_eid: ctx.accounts.offer.dst_eid,
+                    receiver: peer.address,
+                    message: payload,
+                    options: enforced_options.combine_options(&None, extra_options)?,
+                    native_fee: fee.native_fee,
+                    lz_token_fee: fee.lz_token_fee,
+                }
+            )?;
+        }
+
+        Ok(receipt)
+    }
+}
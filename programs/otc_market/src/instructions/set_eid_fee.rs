@@ -0,0 +1,57 @@
+use crate::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(params: SetEidFeeParams)]
+pub struct SetEidFee<'info> {
+    #[account(
+        mut,
+        constraint = otc_config.fee_manager == fee_manager.key() @ OtcError::Unauthorized
+    )]
+    pub fee_manager: Signer<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = fee_manager,
+        space = 8 + FeeOverride::INIT_SPACE,
+        seeds = [
+            FeeOverride::EID_FEE_SEED.as_ref(),
+            otc_config.key().as_ref(),
+            &params.eid.to_be_bytes(),
+        ],
+        bump
+    )]
+    pub eid_fee: Account<'info, FeeOverride>,
+
+    #[account(seeds = [OtcConfig::OTC_SEED], bump = otc_config.bump)]
+    pub otc_config: Account<'info, OtcConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl SetEidFee<'_> {
+    pub fn apply(ctx: &mut Context<SetEidFee>, params: &SetEidFeeParams) -> Result<()> {
+        if let Some(fee_bps) = params.fee_bps {
+            require!(fee_bps <= OtcConfig::MAX_FEE_BPS, OtcError::ExcessiveFee);
+        }
+
+        ctx.accounts.eid_fee.fee_bps = params.fee_bps;
+        ctx.accounts.eid_fee.bump = ctx.bumps.eid_fee;
+
+        emit_cpi!(FeeOverrideChanged {
+            dst_token_address: None,
+            eid: Some(params.eid),
+            fee_bps: params.fee_bps,
+        });
+
+        Ok(())
+    }
+}
+
+#[derive(Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct SetEidFeeParams {
+    /// NOTICE: matched against the offer src_eid - the counterparty chain of the accepted offer
+    pub eid: u32,
+    /// NOTICE: None removes the override
+    pub fee_bps: Option<u16>,
+}
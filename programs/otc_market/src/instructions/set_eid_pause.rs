@@ -0,0 +1,44 @@
+use crate::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(params: SetEidPauseParams)]
+pub struct SetEidPause<'info> {
+    #[account(mut, constraint = otc_config.pauser == pauser.key() @ OtcError::Unauthorized)]
+    pub pauser: Signer<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = pauser,
+        space = 8 + EidPause::INIT_SPACE,
+        seeds = [EidPause::PAUSE_SEED, otc_config.key().as_ref(), &params.eid.to_be_bytes()],
+        bump
+    )]
+    pub eid_pause: Account<'info, EidPause>,
+
+    #[account(seeds = [OtcConfig::OTC_SEED], bump = otc_config.bump)]
+    pub otc_config: Account<'info, OtcConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl SetEidPause<'_> {
+    pub fn apply(ctx: &mut Context<SetEidPause>, params: &SetEidPauseParams) -> Result<()> {
+        ctx.accounts.eid_pause.paused = params.paused;
+        ctx.accounts.eid_pause.bump = ctx.bumps.eid_pause;
+
+        emit_cpi!(PauseChanged {
+            eid: Some(params.eid),
+            paused: params.paused,
+            pause_cancellations: ctx.accounts.otc_config.pause_cancellations,
+        });
+
+        Ok(())
+    }
+}
+
+#[derive(Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct SetEidPauseParams {
+    pub eid: u32,
+    pub paused: bool,
+}
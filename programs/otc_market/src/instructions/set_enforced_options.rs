@@ -5,13 +5,13 @@ use crate::*;
 pub struct SetEnforcedOptions<'info> {
     #[account(
         mut,
-        constraint = otc_config.admin == admin.key() @ OtcError::Unauthorized,
+        constraint = otc_config.options_manager == options_manager.key() @ OtcError::Unauthorized,
     )]
-    pub admin: Signer<'info>,
+    pub options_manager: Signer<'info>,
 
     #[account(
         init_if_needed,
-        payer = admin,
+        payer = options_manager,
         space = 8 + EnforcedOptions::INIT_SPACE,
         seeds = [
             EnforcedOptions::ENFORCED_OPTIONS_SEED,
@@ -0,0 +1,37 @@
+use crate::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetFee<'info> {
+    #[account(constraint = otc_config.fee_manager == fee_manager.key() @ OtcError::Unauthorized)]
+    pub fee_manager: Signer<'info>,
+
+    #[account(mut, seeds = [OtcConfig::OTC_SEED], bump = otc_config.bump)]
+    pub otc_config: Account<'info, OtcConfig>,
+}
+
+impl SetFee<'_> {
+    pub fn apply(ctx: &mut Context<SetFee>, params: &SetFeeParams) -> Result<()> {
+        require!(
+            params.maker_fee_bps <= OtcConfig::MAX_FEE_BPS &&
+                params.taker_fee_bps <= OtcConfig::MAX_FEE_BPS,
+            OtcError::ExcessiveFee
+        );
+
+        ctx.accounts.otc_config.maker_fee_bps = params.maker_fee_bps;
+        ctx.accounts.otc_config.taker_fee_bps = params.taker_fee_bps;
+
+        emit_cpi!(FeeChanged {
+            maker_fee_bps: params.maker_fee_bps,
+            taker_fee_bps: params.taker_fee_bps,
+        });
+
+        Ok(())
+    }
+}
+
+#[derive(Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct SetFeeParams {
+    pub maker_fee_bps: u16,
+    pub taker_fee_bps: u16,
+}
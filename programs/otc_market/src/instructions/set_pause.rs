@@ -0,0 +1,33 @@
+use crate::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetPause<'info> {
+    #[account(constraint = otc_config.pauser == pauser.key() @ OtcError::Unauthorized)]
+    pub pauser: Signer<'info>,
+
+    #[account(mut, seeds = [OtcConfig::OTC_SEED], bump = otc_config.bump)]
+    pub otc_config: Account<'info, OtcConfig>,
+}
+
+impl SetPause<'_> {
+    pub fn apply(ctx: &mut Context<SetPause>, params: &SetPauseParams) -> Result<()> {
+        ctx.accounts.otc_config.paused = params.paused;
+        ctx.accounts.otc_config.pause_cancellations = params.pause_cancellations;
+
+        emit_cpi!(PauseChanged {
+            eid: None,
+            paused: params.paused,
+            pause_cancellations: params.pause_cancellations,
+        });
+
+        Ok(())
+    }
+}
+
+#[derive(Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct SetPauseParams {
+    pub paused: bool,
+    /// NOTICE: false keeps cancellations and refunds enabled while paused
+    pub pause_cancellations: bool,
+}
@@ -5,13 +5,13 @@ use crate::*;
 pub struct SetPeer<'info> {
     #[account(
         mut,
-        constraint = otc_config.admin == admin.key() @ OtcError::Unauthorized,
+        constraint = otc_config.peer_manager == peer_manager.key() @ OtcError::Unauthorized,
     )]
-    pub admin: Signer<'info>,
+    pub peer_manager: Signer<'info>,
 
     #[account(
         init_if_needed,
-        payer = admin,
+        payer = peer_manager,
         space = 8 + Peer::INIT_SPACE,
         seeds = [Peer::PEER_SEED, otc_config.key().as_ref(), &params.dst_eid.to_be_bytes()],
         bump
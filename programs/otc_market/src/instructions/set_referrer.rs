@@ -0,0 +1,52 @@
+use crate::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(params: SetReferrerParams)]
+pub struct SetReferrer<'info> {
+    #[account(
+        mut,
+        constraint = otc_config.fee_manager == fee_manager.key() @ OtcError::Unauthorized
+    )]
+    pub fee_manager: Signer<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = fee_manager,
+        space = 8 + Referrer::INIT_SPACE,
+        seeds = [Referrer::REFERRER_SEED.as_ref(), params.referrer.as_ref()],
+        bump
+    )]
+    pub referrer_config: Account<'info, Referrer>,
+
+    #[account(seeds = [OtcConfig::OTC_SEED], bump = otc_config.bump)]
+    pub otc_config: Account<'info, OtcConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl SetReferrer<'_> {
+    pub fn apply(ctx: &mut Context<SetReferrer>, params: &SetReferrerParams) -> Result<()> {
+        require!(
+            (params.max_share_bps as u64) <= OtcConfig::BPS_DENOMINATOR,
+            OtcError::ExcessiveReferrerShare
+        );
+
+        ctx.accounts.referrer_config.max_share_bps = params.max_share_bps;
+        ctx.accounts.referrer_config.bump = ctx.bumps.referrer_config;
+
+        emit_cpi!(ReferrerChanged {
+            referrer: params.referrer,
+            max_share_bps: params.max_share_bps,
+        });
+
+        Ok(())
+    }
+}
+
+#[derive(Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct SetReferrerParams {
+    pub referrer: Pubkey,
+    /// NOTICE: 0 disables the referrer
+    pub max_share_bps: u16,
+}
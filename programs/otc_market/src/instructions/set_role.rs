@@ -0,0 +1,33 @@
+use crate::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetRole<'info> {
+    #[account(constraint = otc_config.admin == admin.key() @ OtcError::Unauthorized)]
+    pub admin: Signer<'info>,
+
+    #[account(mut, seeds = [OtcConfig::OTC_SEED], bump = otc_config.bump)]
+    pub otc_config: Account<'info, OtcConfig>,
+}
+
+impl SetRole<'_> {
+    pub fn apply(ctx: &mut Context<SetRole>, params: &SetRoleParams) -> Result<()> {
+        let old_account = ctx.accounts.otc_config.role(params.role);
+
+        ctx.accounts.otc_config.set_role(params.role, params.account);
+
+        emit_cpi!(RoleChanged {
+            role: params.role,
+            old_account,
+            new_account: params.account,
+        });
+
+        Ok(())
+    }
+}
+
+#[derive(Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct SetRoleParams {
+    pub role: Role,
+    pub account: Pubkey,
+}
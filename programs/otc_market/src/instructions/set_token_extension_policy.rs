@@ -0,0 +1,38 @@
+use crate::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetTokenExtensionPolicy<'info> {
+    #[account(constraint = otc_config.admin == admin.key() @ OtcError::Unauthorized)]
+    pub admin: Signer<'info>,
+
+    #[account(mut, seeds = [OtcConfig::OTC_SEED], bump = otc_config.bump)]
+    pub otc_config: Account<'info, OtcConfig>,
+}
+
+impl SetTokenExtensionPolicy<'_> {
+    pub fn apply(
+        ctx: &mut Context<SetTokenExtensionPolicy>,
+        params: &SetTokenExtensionPolicyParams
+    ) -> Result<()> {
+        let known_flags = OtcConfig::ALLOW_CONFIDENTIAL_TRANSFER;
+        require!(
+            params.token_extension_policy & !known_flags == 0,
+            OtcError::UnsupportedTokenExtension
+        );
+
+        ctx.accounts.otc_config.token_extension_policy = params.token_extension_policy;
+
+        emit_cpi!(TokenExtensionPolicyChanged {
+            token_extension_policy: params.token_extension_policy,
+        });
+
+        Ok(())
+    }
+}
+
+#[derive(Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct SetTokenExtensionPolicyParams {
+    /// NOTICE: OtcConfig::ALLOW_* flags, 0 - only mints without these extensions
+    pub token_extension_policy: u8,
+}
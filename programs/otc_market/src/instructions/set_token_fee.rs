@@ -0,0 +1,56 @@
+use crate::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(params: SetTokenFeeParams)]
+pub struct SetTokenFee<'info> {
+    #[account(
+        mut,
+        constraint = otc_config.fee_manager == fee_manager.key() @ OtcError::Unauthorized
+    )]
+    pub fee_manager: Signer<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = fee_manager,
+        space = 8 + FeeOverride::INIT_SPACE,
+        seeds = [
+            FeeOverride::TOKEN_FEE_SEED.as_ref(),
+            otc_config.key().as_ref(),
+            params.dst_token_address.as_ref(),
+        ],
+        bump
+    )]
+    pub token_fee: Account<'info, FeeOverride>,
+
+    #[account(seeds = [OtcConfig::OTC_SEED], bump = otc_config.bump)]
+    pub otc_config: Account<'info, OtcConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl SetTokenFee<'_> {
+    pub fn apply(ctx: &mut Context<SetTokenFee>, params: &SetTokenFeeParams) -> Result<()> {
+        if let Some(fee_bps) = params.fee_bps {
+            require!(fee_bps <= OtcConfig::MAX_FEE_BPS, OtcError::ExcessiveFee);
+        }
+
+        ctx.accounts.token_fee.fee_bps = params.fee_bps;
+        ctx.accounts.token_fee.bump = ctx.bumps.token_fee;
+
+        emit_cpi!(FeeOverrideChanged {
+            dst_token_address: Some(params.dst_token_address),
+            eid: None,
+            fee_bps: params.fee_bps,
+        });
+
+        Ok(())
+    }
+}
+
+#[derive(Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct SetTokenFeeParams {
+    pub dst_token_address: [u8; 32],
+    /// NOTICE: None removes the override
+    pub fee_bps: Option<u16>,
+}
@@ -0,0 +1,42 @@
+use crate::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetTreasury<'info> {
+    #[account(constraint = otc_config.admin == admin.key() @ OtcError::Unauthorized)]
+    pub admin: Signer<'info>,
+
+    #[account(mut, seeds = [OtcConfig::OTC_SEED], bump = otc_config.bump)]
+    pub otc_config: Account<'info, OtcConfig>,
+}
+
+impl SetTreasury<'_> {
+    pub fn apply(ctx: &mut Context<SetTreasury>, params: &SetTreasuryParams) -> Result<()> {
+        // fees always accrue in the treasury vault, the treasury wallet is only a withdrawal destination
+        let (treasury_vault, _) = Pubkey::find_program_address(
+            &[Treasury::TREASURY_SEED],
+            ctx.program_id
+        );
+        require!(
+            params.treasury != Pubkey::default() &&
+                params.treasury != ctx.accounts.otc_config.key() &&
+                params.treasury != treasury_vault,
+            OtcError::InvalidTreasury
+        );
+
+        let old_treasury = ctx.accounts.otc_config.treasury;
+        ctx.accounts.otc_config.treasury = params.treasury;
+
+        emit_cpi!(TreasuryChanged {
+            old_treasury,
+            new_treasury: params.treasury,
+        });
+
+        Ok(())
+    }
+}
+
+#[derive(Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct SetTreasuryParams {
+    pub treasury: Pubkey,
+}
@@ -0,0 +1,119 @@
+use crate::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{ Mint, TokenAccount, TokenInterface },
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(offer_id: [u8; 32])]
+pub struct SweepEscrowSurplus<'info> {
+    #[account(mut, constraint = otc_config.admin == admin.key() @ OtcError::Unauthorized)]
+    pub admin: Signer<'info>,
+
+    #[account(seeds = [OtcConfig::OTC_SEED], bump = otc_config.bump)]
+    pub otc_config: Account<'info, OtcConfig>,
+
+    #[account(
+        seeds = [&offer_id],
+        bump = offer.bump,
+        constraint = offer.src_eid == otc_config.eid @ OtcError::InvalidEid
+    )]
+    pub offer: Account<'info, Offer>,
+
+    #[account(mut, seeds = [Escrow::ESCROW_SEED, &offer_id], bump = escrow.bump)]
+    /// NOTICE: sol surplus - from | spl surplus - authority of from_ata
+    pub escrow: Account<'info, Escrow>,
+
+    #[account(
+        mut,
+        constraint = destination.key() == otc_config.treasury @ OtcError::InvalidTreasury
+    )]
+    /// CHECK: asserted against the one stored in the otc config
+    /// NOTICE: required for sol surplus - to | required for spl surplus - (init_if_needed)
+    pub destination: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        associated_token::authority = escrow,
+        associated_token::mint = token_mint,
+        associated_token::token_program = token_program
+    )]
+    /// NOTICE: required for spl surplus - from_ata
+    pub escrow_ata: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        associated_token::authority = destination,
+        associated_token::mint = token_mint,
+        associated_token::token_program = token_program
+    )]
+    /// NOTICE: required for spl surplus - to_ata
+    pub destination_ata: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mint::token_program = token_program)]
+    /// NOTICE: required for spl surplus - token_mint
+    pub token_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    pub associated_token_program: Option<Program<'info, AssociatedToken>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl SweepEscrowSurplus<'_> {
+    /// NOTICE: sweeps a single offer vault down to the liability of its offer, which is what
+    /// the [EscrowLedger] accounts for it - the ledger total is only checked by verify_escrow
+    pub fn apply(ctx: &mut Context<SweepEscrowSurplus>, offer_id: &[u8; 32]) -> Result<u64> {
+        let token_mint = ctx.accounts.token_mint.as_ref();
+
+        let token_address = OtcConfig::get_token_address(token_mint);
+        require!(
+            token_address == ctx.accounts.offer.src_token_address,
+            OtcError::InvalidSrcTokenMint
+        );
+
+        // what the offer is owed, the same amount its escrow ledger accounts for
+        let escrowed_ld = OtcConfig::sd2ld(
+            ctx.accounts.offer.src_amount_sd,
+            OtcConfig::get_local_decimals(token_mint)
+        );
+
+        let balance_ld = if token_mint.is_some() {
+            require!(
+                ctx.accounts.destination_ata.is_some() && ctx.accounts.token_program.is_some(),
+                OtcError::MissingAccount
+            );
+            ctx.accounts.escrow_ata.as_ref().ok_or(OtcError::MissingAccount)?.amount
+        } else {
+            let escrow = ctx.accounts.escrow.to_account_info();
+            let rent = Rent::get()?.minimum_balance(escrow.data_len());
+            escrow.lamports().saturating_sub(rent)
+        };
+
+        // only the balance exceeding the liability, the offer stays fully collateralized
+        let amount_ld = balance_ld.saturating_sub(escrowed_ld);
+
+        OtcConfig::transfer(
+            ctx.accounts.escrow.to_account_info().as_ref(),
+            amount_ld,
+            Some(&ctx.accounts.destination),
+            ctx.accounts.token_program.as_ref(),
+            ctx.accounts.escrow_ata.as_ref(),
+            token_mint,
+            ctx.accounts.destination_ata.as_ref(),
+            Some(&[&[Escrow::ESCROW_SEED, offer_id, &[ctx.accounts.escrow.bump]]])
+        )?;
+
+        emit_cpi!(EscrowSurplusSwept {
+            offer_id: *offer_id,
+            token_address,
+            destination: ctx.accounts.destination.key(),
+            amount_ld,
+        });
+
+        Ok(amount_ld)
+    }
+}
@@ -0,0 +1,290 @@
+use crate::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{ Mint, TokenAccount, TokenInterface },
+};
+use oapp::endpoint::{
+    instructions::SendParams as EndpointSendParams,
+    MessagingFee,
+    MessagingReceipt,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(params: TopUpOfferParams, fee: MessagingFee)]
+pub struct TopUpOffer<'info> {
+    #[account(mut)]
+    pub seller: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [&params.offer_id],
+        bump = offer.bump,
+        constraint = offer.src_eid == otc_config.eid @ OtcError::InvalidEid,
+        constraint = offer.src_seller_address == seller.key().to_bytes() @ OtcError::OnlySeller,
+        constraint = !offer.cancel_pending @ OtcError::CancelPending
+    )]
+    pub offer: Box<Account<'info, Offer>>,
+
+    #[account(
+        init_if_needed,
+        payer = seller,
+        space = 8 + FeeLedger::INIT_SPACE,
+        seeds = [FeeLedger::FEE_LEDGER_SEED, offer.src_token_address.as_ref()],
+        bump
+    )]
+    /// NOTICE: required for non-zero maker fee
+    pub fee_ledger: Option<Box<Account<'info, FeeLedger>>>,
+
+    #[account(
+        init_if_needed,
+        payer = seller,
+        space = 8 + EscrowLedger::INIT_SPACE,
+        seeds = [EscrowLedger::ESCROW_LEDGER_SEED, offer.src_token_address.as_ref()],
+        bump
+    )]
+    pub escrow_ledger: Box<Account<'info, EscrowLedger>>,
+
+    #[account(seeds = [OtcConfig::OTC_SEED], bump = otc_config.bump)]
+    pub otc_config: Box<Account<'info, OtcConfig>>,
+
+    #[account(seeds = [EidPause::PAUSE_SEED, otc_config.key().as_ref(), &offer.dst_eid.to_be_bytes()], bump)]
+    /// CHECK: eid pause switch, may be uninitialized
+    pub eid_pause: AccountInfo<'info>,
+
+    #[account(mut, seeds = [Escrow::ESCROW_SEED, &params.offer_id], bump = escrow.bump)]
+    pub escrow: Box<Account<'info, Escrow>>,
+
+    #[account(
+        mint::token_program = token_program,
+        constraint = src_token_mint.key() == Pubkey::new_from_array(offer.src_token_address) @ OtcError::InvalidSrcTokenMint
+    )]
+    /// NOTICE: required for src spl offer
+    pub src_token_mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+
+    #[account(
+        mut,
+        associated_token::authority = seller,
+        associated_token::mint = src_token_mint,
+        associated_token::token_program = token_program,
+    )]
+    /// NOTICE: required for src spl offer
+    pub src_seller_ata: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(
+        mut, // for sure created in create_offer instruction
+        associated_token::authority = escrow,
+        associated_token::mint = src_token_mint,
+        associated_token::token_program = token_program
+    )]
+    /// NOTICE: required for src spl offer
+    pub src_escrow_ata: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(mut, seeds = [Treasury::TREASURY_SEED], bump = treasury.bump)]
+    /// NOTICE: src sol offer - maker fee: to | src spl offer - maker fee: authority of to_ata
+    pub treasury: Box<Account<'info, Treasury>>,
+
+    #[account(
+        init_if_needed,
+        payer = seller,
+        associated_token::authority = treasury,
+        associated_token::mint = src_token_mint,
+        associated_token::token_program = token_program
+    )]
+    /// NOTICE: required for src spl offer with non-zero maker fee - maker fee: to_ata
+    pub src_treasury_ata: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(
+        seeds = [Peer::PEER_SEED, otc_config.key().as_ref(), &offer.dst_eid.to_be_bytes()],
+        bump = peer.bump
+    )]
+    /// NOTICE: required for crosschain offer
+    pub peer: Option<Box<Account<'info, Peer>>>,
+
+    #[account(
+        seeds = [
+            EnforcedOptions::ENFORCED_OPTIONS_SEED,
+            otc_config.key().as_ref(),
+            &offer.dst_eid.to_be_bytes(),
+        ],
+        bump = enforced_options.bump
+    )]
+    /// NOTICE: required for crosschain offer
+    pub enforced_options: Option<Box<Account<'info, EnforcedOptions>>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    pub associated_token_program: Option<Program<'info, AssociatedToken>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl TopUpOffer<'_> {
+    pub fn apply(
+        ctx: &mut Context<TopUpOffer>,
+        params: &TopUpOfferParams,
+        fee: &MessagingFee
+    ) -> Result<(TopUpOfferReceipt, MessagingReceipt)> {
+        ctx.accounts.otc_config.assert_not_paused(&ctx.accounts.eid_pause)?;
+
+        require!(
+            !ctx.accounts.offer.is_expired(Clock::get()?.unix_timestamp),
+            OtcError::OfferExpired
+        );
+
+        let maker_fee_bps = ctx.accounts.otc_config.maker_fee_bps;
+
+        let (src_amount_sd, src_amount_ld, maker_fee_sd, maker_fee_ld): (u64, u64, u64, u64);
+        let (escrowed_amount_sd, escrowed_amount_ld): (u64, u64);
+        let (escrow_transfer_fee_ld, treasury_transfer_fee_ld): (u64, u64);
+        {
+            let local_decimals = OtcConfig::get_local_decimals(
+                ctx.accounts.src_token_mint.as_deref()
+            );
+            (src_amount_sd, src_amount_ld) = OtcConfig::remove_dust(
+                params.src_amount_ld,
+                local_decimals
+            )?;
+            maker_fee_sd = OtcConfig::to_maker_fee(src_amount_sd, maker_fee_bps, local_decimals);
+            maker_fee_ld = OtcConfig::sd2ld(maker_fee_sd, local_decimals);
+
+            // token-2022 transfer fee is withheld from what the escrow and the treasury receive
+            escrow_transfer_fee_ld = OtcConfig::get_transfer_fee(
+                ctx.accounts.src_token_mint.as_deref(),
+                src_amount_ld - maker_fee_ld
+            )?;
+            treasury_transfer_fee_ld = OtcConfig::get_transfer_fee(
+                ctx.accounts.src_token_mint.as_deref(),
+                maker_fee_ld
+            )?;
+            escrowed_amount_ld = src_amount_ld - maker_fee_ld - escrow_transfer_fee_ld;
+            escrowed_amount_sd = OtcConfig::ld2sd(escrowed_amount_ld, local_decimals)?;
+        }
+
+        // validate amount
+        require!(escrowed_amount_sd != 0, OtcError::InvalidAmount);
+
+        // update state
+        // maker fee and transfer fee are not escrowed
+        ctx.accounts.offer.src_amount_sd = ctx.accounts.offer.src_amount_sd
+            .checked_add(escrowed_amount_sd)
+            .ok_or(OtcError::Overflow)?;
+
+        // emit event
+        emit_cpi!(OfferToppedUp {
+            offer_id: params.offer_id,
+            src_amount_sd: escrowed_amount_sd,
+        });
+
+        let mut receipt = MessagingReceipt::default();
+
+        if ctx.accounts.offer.dst_eid != ctx.accounts.otc_config.eid {
+            // crosschain offer
+
+            let peer = ctx.accounts.peer.as_ref().expect(OtcConfig::ERROR_MSG);
+            let enforced_options = ctx.accounts.enforced_options
+                .as_ref()
+                .expect(OtcConfig::ERROR_MSG);
+
+            let payload = build_top_up_offer_payload(
+                &params.offer_id,
+                &ctx.accounts.offer,
+                escrowed_amount_sd
+            );
+
+            receipt = oapp::endpoint_cpi::send(
+                ctx.accounts.otc_config.endpoint_program,
+                ctx.accounts.otc_config.key(),
+                ctx.remaining_accounts,
+                &[OtcConfig::OTC_SEED, &[ctx.accounts.otc_config.bump]],
+                EndpointSendParams {
+                    dst_eid: ctx.accounts.offer.dst_eid,
+                    receiver: peer.address,
+                    message: payload,
+                    options: enforced_options.get_enforced_options(&None),
+                    native_fee: fee.native_fee,
+                    lz_token_fee: fee.lz_token_fee,
+                }
+            )?;
+        }
+
+        // (amount - maker fee) to escrow
+        OtcConfig::transfer(
+            ctx.accounts.seller.as_ref(),
+            src_amount_ld - maker_fee_ld,
+            Some(&ctx.accounts.escrow.to_account_info()),
+            ctx.accounts.token_program.as_ref(),
+            ctx.accounts.src_seller_ata.as_deref(),
+            ctx.accounts.src_token_mint.as_deref(),
+            ctx.accounts.src_escrow_ata.as_deref(),
+            None
+        )?;
+
+        // maker fee to treasury
+        if maker_fee_ld != 0 {
+            require!(
+                ctx.accounts.src_token_mint.is_none() || ctx.accounts.src_treasury_ata.is_some(),
+                OtcError::MissingAccount
+            );
+
+            OtcConfig::transfer(
+                ctx.accounts.seller.as_ref(),
+                maker_fee_ld,
+                Some(&ctx.accounts.treasury.to_account_info()),
+                ctx.accounts.token_program.as_ref(),
+                ctx.accounts.src_seller_ata.as_deref(),
+                ctx.accounts.src_token_mint.as_deref(),
+                ctx.accounts.src_treasury_ata.as_deref(),
+                None
+            )?;
+
+            ctx.accounts.fee_ledger
+                .as_mut()
+                .ok_or(OtcError::MissingAccount)?
+                .collect(
+                    &ctx.accounts.offer.src_token_address,
+                    ctx.bumps.fee_ledger,
+                    maker_fee_ld - treasury_transfer_fee_ld
+                );
+        }
+
+        // dust below shared decimals is not owed to the offer
+        ctx.accounts.escrow_ledger.deposit(
+            &ctx.accounts.offer.src_token_address,
+            ctx.bumps.escrow_ledger,
+            OtcConfig::sd2ld(
+                escrowed_amount_sd,
+                OtcConfig::get_local_decimals(ctx.accounts.src_token_mint.as_deref())
+            )
+        );
+
+        Ok((
+            TopUpOfferReceipt {
+                src_amount_ld,
+                maker_fee_ld,
+                maker_fee_bps,
+                escrowed_amount_ld,
+                transfer_fee_ld: escrow_transfer_fee_ld + treasury_transfer_fee_ld,
+            },
+            receipt,
+        ))
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct TopUpOfferParams {
+    pub offer_id: [u8; 32],
+    pub src_amount_ld: u64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct TopUpOfferReceipt {
+    /// NOTICE: total taken from the seller, maker fee included
+    pub src_amount_ld: u64,
+    pub maker_fee_ld: u64,
+    pub maker_fee_bps: u16,
+    /// NOTICE: received by the escrow, net of the maker fee and the token-2022 transfer fee
+    pub escrowed_amount_ld: u64,
+    /// NOTICE: token-2022 transfer fee withheld from the escrow and the treasury
+    pub transfer_fee_ld: u64,
+}
@@ -0,0 +1,117 @@
+use crate::*;
+use oapp::endpoint::{
+    instructions::SendParams as EndpointSendParams,
+    MessagingFee,
+    MessagingReceipt,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(params: UpdateOfferPriceParams, fee: MessagingFee)]
+pub struct UpdateOfferPrice<'info> {
+    pub seller: Signer<'info>,
+
+    #[account(seeds = [OtcConfig::OTC_SEED], bump = otc_config.bump)]
+    pub otc_config: Account<'info, OtcConfig>,
+
+    #[account(
+        mut,
+        seeds = [&params.offer_id],
+        bump = offer.bump,
+        constraint = offer.src_eid == otc_config.eid @ OtcError::InvalidEid,
+        constraint = offer.src_seller_address == seller.key().to_bytes() @ OtcError::OnlySeller
+    )]
+    pub offer: Account<'info, Offer>,
+
+    #[account(seeds = [EidPause::PAUSE_SEED, otc_config.key().as_ref(), &offer.dst_eid.to_be_bytes()], bump)]
+    /// CHECK: eid pause switch, may be uninitialized
+    pub eid_pause: AccountInfo<'info>,
+
+    #[account(
+        seeds = [Peer::PEER_SEED, otc_config.key().as_ref(), &offer.dst_eid.to_be_bytes()],
+        bump = peer.bump
+    )]
+    /// NOTICE: required for crosschain offer
+    pub peer: Option<Account<'info, Peer>>,
+
+    #[account(
+        seeds = [
+            EnforcedOptions::ENFORCED_OPTIONS_SEED,
+            otc_config.key().as_ref(),
+            &offer.dst_eid.to_be_bytes(),
+        ],
+        bump = enforced_options.bump
+    )]
+    /// NOTICE: required for crosschain offer
+    pub enforced_options: Option<Account<'info, EnforcedOptions>>,
+}
+
+impl UpdateOfferPrice<'_> {
+    pub fn apply(
+        ctx: &mut Context<UpdateOfferPrice>,
+        params: &UpdateOfferPriceParams,
+        fee: &MessagingFee
+    ) -> Result<MessagingReceipt> {
+        ctx.accounts.otc_config.assert_not_paused(&ctx.accounts.eid_pause)?;
+
+        require!(
+            !ctx.accounts.offer.is_expired(Clock::get()?.unix_timestamp),
+            OtcError::OfferExpired
+        );
+
+        // validate pricing
+        require!(params.exchange_rate_sd != 0, OtcError::InvalidPricing);
+
+        // update state - the offer id stays the same
+        ctx.accounts.offer.exchange_rate_sd = params.exchange_rate_sd;
+        ctx.accounts.offer.price_revision = ctx.accounts.offer.price_revision
+            .checked_add(1)
+            .ok_or(OtcError::Overflow)?;
+
+        // emit event
+        emit_cpi!(OfferRepriced {
+            offer_id: params.offer_id,
+            exchange_rate_sd: params.exchange_rate_sd,
+        });
+
+        let mut receipt = MessagingReceipt::default();
+
+        if ctx.accounts.offer.dst_eid != ctx.accounts.otc_config.eid {
+            // crosschain offer
+
+            let peer = ctx.accounts.peer.as_ref().expect(OtcConfig::ERROR_MSG);
+            let enforced_options = ctx.accounts.enforced_options
+                .as_ref()
+                .expect(OtcConfig::ERROR_MSG);
+
+            let payload = build_offer_repriced_payload(
+                &params.offer_id,
+                params.exchange_rate_sd,
+                ctx.accounts.offer.price_revision
+            );
+
+            receipt = oapp::endpoint_cpi::send(
+                ctx.accounts.otc_config.endpoint_program,
+                ctx.accounts.otc_config.key(),
+                ctx.remaining_accounts,
+                &[OtcConfig::OTC_SEED, &[ctx.accounts.otc_config.bump]],
+                EndpointSendParams {
+                    dst_eid: ctx.accounts.offer.dst_eid,
+                    receiver: peer.address,
+                    message: payload,
+                    options: enforced_options.get_enforced_options(&None),
+                    native_fee: fee.native_fee,
+                    lz_token_fee: fee.lz_token_fee,
+                }
+            )?;
+        }
+
+        Ok(receipt)
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct UpdateOfferPriceParams {
+    pub offer_id: [u8; 32],
+    pub exchange_rate_sd: u64,
+}
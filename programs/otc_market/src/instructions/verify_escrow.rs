@@ -0,0 +1,100 @@
+use crate::*;
+use anchor_spl::{
+    associated_token::get_associated_token_address_with_program_id,
+    token_interface::{ Mint, TokenAccount, TokenInterface },
+};
+
+#[derive(Accounts)]
+pub struct VerifyEscrow<'info> {
+    #[account(
+        seeds = [
+            EscrowLedger::ESCROW_LEDGER_SEED,
+            OtcConfig::get_token_address(token_mint.as_ref()).as_ref(),
+        ],
+        bump = escrow_ledger.bump
+    )]
+    pub escrow_ledger: Account<'info, EscrowLedger>,
+
+    #[account(mint::token_program = token_program)]
+    /// NOTICE: required for spl token
+    pub token_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    /// NOTICE: required for spl token
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+}
+
+impl VerifyEscrow<'_> {
+    /// NOTICE: remaining accounts are the escrow vaults to sum up, in ascending escrow address order -
+    /// escrow for sol, (escrow, escrow ata) pairs for spl token
+    pub fn apply(ctx: &mut Context<VerifyEscrow>) -> Result<EscrowReport> {
+        let token_mint = ctx.accounts.token_mint.as_ref();
+
+        let vaults = ctx.remaining_accounts.chunks_exact(if token_mint.is_some() { 2 } else { 1 });
+        require!(vaults.remainder().is_empty(), OtcError::InvalidEscrowVault);
+
+        let mut balance_ld: u64 = 0;
+        let mut vault_count: u64 = 0;
+        let mut last_escrow = Pubkey::default();
+        for vault in vaults {
+            let escrow = &vault[0];
+            require!(escrow.owner == ctx.program_id, OtcError::InvalidEscrowVault);
+            // an escrow vault of another token would be counted in
+            require!(
+                Escrow::try_deserialize(&mut &escrow.try_borrow_data()?[..]).is_ok_and(
+                    |escrow| escrow.token_address == ctx.accounts.escrow_ledger.token_address
+                ),
+                OtcError::InvalidEscrowVault
+            );
+
+            // ascending order rules out counting a vault twice
+            require!(escrow.key() > last_escrow, OtcError::InvalidEscrowVault);
+            last_escrow = escrow.key();
+
+            let vault_balance_ld = if let Some(token_mint) = token_mint {
+                let token_program = ctx.accounts.token_program.as_ref().expect(OtcConfig::ERROR_MSG);
+                require!(
+                    vault[1].key() ==
+                        get_associated_token_address_with_program_id(
+                            &escrow.key(),
+                            &token_mint.key(),
+                            &token_program.key()
+                        ),
+                    OtcError::InvalidEscrowVault
+                );
+                TokenAccount::try_deserialize(&mut &vault[1].try_borrow_data()?[..])?.amount
+            } else {
+                let rent = Rent::get()?.minimum_balance(escrow.data_len());
+                escrow.lamports().saturating_sub(rent)
+            };
+
+            balance_ld = balance_ld.checked_add(vault_balance_ld).ok_or(OtcError::Overflow)?;
+            vault_count += 1;
+        }
+
+        let escrow_ledger = &ctx.accounts.escrow_ledger;
+        let is_complete = vault_count == escrow_ledger.vaults;
+
+        Ok(EscrowReport {
+            token_address: escrow_ledger.token_address,
+            escrowed_ld: escrow_ledger.escrowed_ld,
+            balance_ld,
+            vaults: vault_count,
+            is_complete,
+            is_solvent: is_complete && balance_ld >= escrow_ledger.escrowed_ld,
+        })
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct EscrowReport {
+    pub token_address: [u8; 32],
+    /// NOTICE: outstanding liabilities of all offers in the token, see [EscrowLedger]
+    pub escrowed_ld: u64,
+    /// NOTICE: sum of the given vaults, rent excluded
+    pub balance_ld: u64,
+    pub vaults: u64,
+    /// NOTICE: every open vault of the token was given
+    pub is_complete: bool,
+    /// NOTICE: every open vault was given and together they cover the outstanding liabilities
+    pub is_solvent: bool,
+}
@@ -0,0 +1,98 @@
+use crate::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{ Mint, TokenAccount, TokenInterface },
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct WithdrawFees<'info> {
+    #[account(
+        mut,
+        constraint = otc_config.fee_manager == fee_manager.key() @ OtcError::Unauthorized
+    )]
+    pub fee_manager: Signer<'info>,
+
+    #[account(seeds = [OtcConfig::OTC_SEED], bump = otc_config.bump)]
+    pub otc_config: Account<'info, OtcConfig>,
+
+    #[account(mut, seeds = [Treasury::TREASURY_SEED], bump = treasury.bump)]
+    pub treasury: Account<'info, Treasury>,
+
+    #[account(
+        mut,
+        seeds = [
+            FeeLedger::FEE_LEDGER_SEED,
+            OtcConfig::get_token_address(token_mint.as_ref()).as_ref(),
+        ],
+        bump = fee_ledger.bump
+    )]
+    pub fee_ledger: Account<'info, FeeLedger>,
+
+    #[account(
+        mut,
+        constraint = destination.key() == otc_config.treasury @ OtcError::InvalidTreasury
+    )]
+    /// CHECK: asserted against the one stored in the otc config
+    /// NOTICE: required for sol fees - to | required for spl fees - (init_if_needed)
+    pub destination: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        associated_token::authority = treasury,
+        associated_token::mint = token_mint,
+        associated_token::token_program = token_program
+    )]
+    /// NOTICE: required for spl fees - from_ata
+    pub treasury_ata: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = fee_manager,
+        associated_token::authority = destination,
+        associated_token::mint = token_mint,
+        associated_token::token_program = token_program
+    )]
+    /// NOTICE: required for spl fees - to_ata
+    pub destination_ata: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mint::token_program = token_program)]
+    /// NOTICE: required for spl fees - token_mint
+    pub token_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    pub associated_token_program: Option<Program<'info, AssociatedToken>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl WithdrawFees<'_> {
+    pub fn apply(ctx: &mut Context<WithdrawFees>) -> Result<u64> {
+        // only fees booked in the ledger are withdrawn, anything sent to the vault directly stays there
+        let amount_ld = ctx.accounts.fee_ledger.collected_ld
+            .checked_sub(ctx.accounts.fee_ledger.withdrawn_ld)
+            .ok_or(OtcError::Overflow)?;
+
+        OtcConfig::transfer(
+            ctx.accounts.treasury.to_account_info().as_ref(),
+            amount_ld,
+            Some(&ctx.accounts.destination),
+            ctx.accounts.token_program.as_ref(),
+            ctx.accounts.treasury_ata.as_ref(),
+            ctx.accounts.token_mint.as_ref(),
+            ctx.accounts.destination_ata.as_ref(),
+            Some(&[&[Treasury::TREASURY_SEED, &[ctx.accounts.treasury.bump]]])
+        )?;
+
+        ctx.accounts.fee_ledger.withdrawn_ld += amount_ld;
+
+        emit_cpi!(FeesWithdrawn {
+            token_address: ctx.accounts.fee_ledger.token_address,
+            destination: ctx.accounts.destination.key(),
+            amount_ld,
+        });
+
+        Ok(amount_ld)
+    }
+}
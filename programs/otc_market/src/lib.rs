@@ -25,6 +25,92 @@ pub mod otc_market {
         Initialize::apply(&mut ctx, &params)
     }
 
+    /// see [propose_admin]
+    pub fn propose_admin(mut ctx: Context<ProposeAdmin>, params: ProposeAdminParams) -> Result<()> {
+        ProposeAdmin::apply(&mut ctx, &params)
+    }
+
+    /// see [accept_admin]
+    pub fn accept_admin(mut ctx: Context<AcceptAdmin>) -> Result<()> {
+        AcceptAdmin::apply(&mut ctx)
+    }
+
+    /// see [set_role]
+    pub fn set_role(mut ctx: Context<SetRole>, params: SetRoleParams) -> Result<()> {
+        SetRole::apply(&mut ctx, &params)
+    }
+
+    /// see [set_treasury]
+    pub fn set_treasury(mut ctx: Context<SetTreasury>, params: SetTreasuryParams) -> Result<()> {
+        SetTreasury::apply(&mut ctx, &params)
+    }
+
+    /// see [withdraw_fees]
+    pub fn withdraw_fees(mut ctx: Context<WithdrawFees>) -> Result<u64> {
+        WithdrawFees::apply(&mut ctx)
+    }
+
+    /// see [verify_escrow]
+    pub fn verify_escrow(mut ctx: Context<VerifyEscrow>) -> Result<EscrowReport> {
+        VerifyEscrow::apply(&mut ctx)
+    }
+
+    /// see [sweep_escrow_surplus]
+    pub fn sweep_escrow_surplus(
+        mut ctx: Context<SweepEscrowSurplus>,
+        offer_id: [u8; 32]
+    ) -> Result<u64> {
+        SweepEscrowSurplus::apply(&mut ctx, &offer_id)
+    }
+
+    /// see [migrate_config]
+    pub fn migrate_config(mut ctx: Context<MigrateConfig>) -> Result<()> {
+        MigrateConfig::apply(&mut ctx)
+    }
+
+    /// see [migrate_offer]
+    pub fn migrate_offer(mut ctx: Context<MigrateOffer>, offer_id: [u8; 32]) -> Result<()> {
+        MigrateOffer::apply(&mut ctx, &offer_id)
+    }
+
+    /// see [set_fee]
+    pub fn set_fee(mut ctx: Context<SetFee>, params: SetFeeParams) -> Result<()> {
+        SetFee::apply(&mut ctx, &params)
+    }
+
+    /// see [set_token_fee]
+    pub fn set_token_fee(mut ctx: Context<SetTokenFee>, params: SetTokenFeeParams) -> Result<()> {
+        SetTokenFee::apply(&mut ctx, &params)
+    }
+
+    /// see [set_eid_fee]
+    pub fn set_eid_fee(mut ctx: Context<SetEidFee>, params: SetEidFeeParams) -> Result<()> {
+        SetEidFee::apply(&mut ctx, &params)
+    }
+
+    /// see [set_referrer]
+    pub fn set_referrer(mut ctx: Context<SetReferrer>, params: SetReferrerParams) -> Result<()> {
+        SetReferrer::apply(&mut ctx, &params)
+    }
+
+    /// see [set_pause]
+    pub fn set_pause(mut ctx: Context<SetPause>, params: SetPauseParams) -> Result<()> {
+        SetPause::apply(&mut ctx, &params)
+    }
+
+    /// see [set_eid_pause]
+    pub fn set_eid_pause(mut ctx: Context<SetEidPause>, params: SetEidPauseParams) -> Result<()> {
+        SetEidPause::apply(&mut ctx, &params)
+    }
+
+    /// see [set_token_extension_policy]
+    pub fn set_token_extension_policy(
+        mut ctx: Context<SetTokenExtensionPolicy>,
+        params: SetTokenExtensionPolicyParams
+    ) -> Result<()> {
+        SetTokenExtensionPolicy::apply(&mut ctx, &params)
+    }
+
     /// see [set_peer]
     pub fn set_peer(mut ctx: Context<SetPeer>, params: SetPeerParams) -> Result<()> {
         SetPeer::apply(&mut ctx, &params)
@@ -56,7 +142,8 @@ pub mod otc_market {
         dst_eid: u32,
         src_token_address: [u8; 32],
         dst_token_address: [u8; 32],
-        exchange_rate_sd: u64
+        exchange_rate_sd: u64,
+        nonce: u64
     ) -> Result<[u8; 32]> {
         HashOffer::apply(
             &src_seller_address,
@@ -64,7 +151,8 @@ pub mod otc_market {
             dst_eid,
             &src_token_address,
             &dst_token_address,
-            exchange_rate_sd
+            exchange_rate_sd,
+            nonce
         )
     }
 
@@ -106,14 +194,44 @@ pub mod otc_market {
         AcceptOffer::apply(&mut ctx, &params, &fee)
     }
 
+    /// see [close_buyer_fill]
+    pub fn close_buyer_fill(mut ctx: Context<CloseBuyerFill>, _offer_id: [u8; 32]) -> Result<()> {
+        CloseBuyerFill::apply(&mut ctx)
+    }
+
+    /// see [quote_top_up_offer]
+    pub fn quote_top_up_offer(
+        mut ctx: Context<QuoteTopUpOffer>,
+        params: TopUpOfferParams,
+        pay_in_lz_token: bool
+    ) -> Result<(TopUpOfferReceipt, MessagingFee)> {
+        QuoteTopUpOffer::apply(&mut ctx, &params, pay_in_lz_token)
+    }
+
+    /// see [top_up_offer]
+    pub fn top_up_offer(
+        mut ctx: Context<TopUpOffer>,
+        params: TopUpOfferParams,
+        fee: MessagingFee
+    ) -> Result<(TopUpOfferReceipt, MessagingReceipt)> {
+        TopUpOffer::apply(&mut ctx, &params, &fee)
+    }
+
     /// see [quote_cancel_offer_order]
     pub fn quote_cancel_offer_order(
         mut ctx: Context<QuoteCancelOfferOrder>,
+        src_seller_address: [u8; 32],
         offer_id: [u8; 32],
         extra_options: Vec<u8>,
         pay_in_lz_token: bool
     ) -> Result<MessagingFee> {
-        QuoteCancelOfferOrder::apply(&mut ctx, &offer_id, &extra_options, pay_in_lz_token)
+        QuoteCancelOfferOrder::apply(
+            &mut ctx,
+            &src_seller_address,
+            &offer_id,
+            &extra_options,
+            pay_in_lz_token
+        )
     }
 
     /// see [quote_cancel_offer]
@@ -134,6 +252,61 @@ pub mod otc_market {
         CancelOffer::apply(&mut ctx, &offer_id, &fee, &extra_options)
     }
 
+    /// see [reclaim_expired_offer]
+    pub fn reclaim_expired_offer(
+        mut ctx: Context<ReclaimExpiredOffer>,
+        offer_id: [u8; 32],
+        fee: MessagingFee,
+        extra_options: Vec<u8>
+    ) -> Result<MessagingReceipt> {
+        ReclaimExpiredOffer::apply(&mut ctx, &offer_id, &fee, &extra_options)
+    }
+
+    /// see [quote_reduce_offer]
+    pub fn quote_reduce_offer(
+        mut ctx: Context<QuoteReduceOffer>,
+        src_seller_address: [u8; 32],
+        params: ReduceOfferParams,
+        extra_options: Vec<u8>,
+        pay_in_lz_token: bool
+    ) -> Result<MessagingFee> {
+        QuoteReduceOffer::apply(
+            &mut ctx,
+            &src_seller_address,
+            &params,
+            &extra_options,
+            pay_in_lz_token
+        )
+    }
+
+    /// see [reduce_offer]
+    pub fn reduce_offer(
+        mut ctx: Context<ReduceOffer>,
+        params: ReduceOfferParams,
+        fee: MessagingFee,
+        extra_options: Vec<u8>
+    ) -> Result<MessagingReceipt> {
+        ReduceOffer::apply(&mut ctx, &params, &fee, &extra_options)
+    }
+
+    /// see [quote_update_offer_price]
+    pub fn quote_update_offer_price(
+        mut ctx: Context<QuoteUpdateOfferPrice>,
+        params: UpdateOfferPriceParams,
+        pay_in_lz_token: bool
+    ) -> Result<MessagingFee> {
+        QuoteUpdateOfferPrice::apply(&mut ctx, &params, pay_in_lz_token)
+    }
+
+    /// see [update_offer_price]
+    pub fn update_offer_price(
+        mut ctx: Context<UpdateOfferPrice>,
+        params: UpdateOfferPriceParams,
+        fee: MessagingFee
+    ) -> Result<MessagingReceipt> {
+        UpdateOfferPrice::apply(&mut ctx, &params, &fee)
+    }
+
     /// see [lz_receive]
     pub fn lz_receive(mut ctx: Context<LzReceive>, params: LzReceiveParams) -> Result<()> {
         LzReceive::apply(&mut ctx, &params)
@@ -1,4 +1,5 @@
 use crate::{ Offer, OtcError };
+use anchor_lang::prelude::Pubkey;
 
 #[repr(u8)]
 pub enum Message {
@@ -6,6 +7,10 @@ pub enum Message {
     OfferAccepted = 1,
     OfferCancelOrder = 2,
     OfferCanceled = 3,
+    OfferToppedUp = 4,
+    OfferReduceOrder = 5,
+    OfferReduced = 6,
+    OfferRepriced = 7,
 }
 
 impl TryFrom<u8> for Message {
@@ -17,14 +22,36 @@ impl TryFrom<u8> for Message {
             1 => Ok(Message::OfferAccepted),
             2 => Ok(Message::OfferCancelOrder),
             3 => Ok(Message::OfferCanceled),
+            4 => Ok(Message::OfferToppedUp),
+            5 => Ok(Message::OfferReduceOrder),
+            6 => Ok(Message::OfferReduced),
+            7 => Ok(Message::OfferRepriced),
             _ => Err(OtcError::InvalidMessageType), // Return an error for unsupported values
         }
     }
 }
 
 pub fn build_create_offer_payload(offer_id: &[u8; 32], offer: &Offer) -> Vec<u8> {
+    build_offer_payload(Message::OfferCreated, offer_id, offer, offer.src_amount_sd)
+}
+
+/// NOTICE: carries the whole offer so the dst chain can bounce a top up of a copy closed meanwhile
+pub fn build_top_up_offer_payload(
+    offer_id: &[u8; 32],
+    offer: &Offer,
+    src_amount_sd: u64
+) -> Vec<u8> {
+    build_offer_payload(Message::OfferToppedUp, offer_id, offer, src_amount_sd)
+}
+
+fn build_offer_payload(
+    message: Message,
+    offer_id: &[u8; 32],
+    offer: &Offer,
+    src_amount_sd: u64
+) -> Vec<u8> {
     [
-        &(Message::OfferCreated as u8).to_be_bytes() as &[u8],
+        &(message as u8).to_be_bytes() as &[u8],
         offer_id,
         &offer.src_seller_address,
         &offer.dst_seller_address,
@@ -32,8 +59,16 @@ pub fn build_create_offer_payload(offer_id: &[u8; 32], offer: &Offer) -> Vec<u8>
         &offer.dst_eid.to_be_bytes(),
         &offer.src_token_address,
         &offer.dst_token_address,
-        &offer.src_amount_sd.to_be_bytes(),
+        &src_amount_sd.to_be_bytes(),
         &offer.exchange_rate_sd.to_be_bytes(),
+        &offer.expires_at.unwrap_or_default().to_be_bytes(), // 0 - no expiry
+        &offer.nonce.to_be_bytes(),
+        &offer.min_fill_sd.to_be_bytes(),
+        &[offer.all_or_nothing as u8],
+        &offer.allowed_buyer.unwrap_or_default(), // zero address - any buyer
+        &offer.buyers_root.unwrap_or_default(), // zero root - no allowlist
+        &offer.src_token_program,
+        &offer.src_granularity_sd.to_be_bytes(),
     ].concat()
 }
 
@@ -41,15 +76,68 @@ pub fn build_cancel_offer_order_payload(offer_id: &[u8; 32]) -> Vec<u8> {
     [&(Message::OfferCancelOrder as u8).to_be_bytes() as &[u8], offer_id].concat()
 }
 
-pub fn build_cancel_offer_payload(offer_id: &[u8; 32]) -> Vec<u8> {
-    [&(Message::OfferCanceled as u8).to_be_bytes() as &[u8], offer_id].concat()
+pub fn build_cancel_offer_payload(
+    offer_id: &[u8; 32],
+    src_seller_address: &[u8; 32],
+    src_token_address: &[u8; 32],
+    src_token_program: &[u8; 32]
+) -> Vec<u8> {
+    [
+        &(Message::OfferCanceled as u8).to_be_bytes() as &[u8],
+        offer_id,
+        src_seller_address,
+        src_token_address,
+        src_token_program,
+    ].concat()
+}
+
+pub fn build_reduce_offer_order_payload(offer_id: &[u8; 32], src_amount_sd: u64) -> Vec<u8> {
+    [
+        &(Message::OfferReduceOrder as u8).to_be_bytes() as &[u8],
+        offer_id,
+        &src_amount_sd.to_be_bytes(),
+    ].concat()
+}
+
+/// NOTICE: offer canceled layout followed by the reduced amount
+pub fn build_offer_reduced_payload(
+    offer_id: &[u8; 32],
+    src_seller_address: &[u8; 32],
+    src_token_address: &[u8; 32],
+    src_token_program: &[u8; 32],
+    src_amount_sd: u64
+) -> Vec<u8> {
+    [
+        &(Message::OfferReduced as u8).to_be_bytes() as &[u8],
+        offer_id,
+        src_seller_address,
+        src_token_address,
+        src_token_program,
+        &src_amount_sd.to_be_bytes(),
+    ].concat()
+}
+
+pub fn build_offer_repriced_payload(
+    offer_id: &[u8; 32],
+    exchange_rate_sd: u64,
+    price_revision: u64
+) -> Vec<u8> {
+    [
+        &(Message::OfferRepriced as u8).to_be_bytes() as &[u8],
+        offer_id,
+        &exchange_rate_sd.to_be_bytes(),
+        &price_revision.to_be_bytes(),
+    ].concat()
 }
 
 pub fn build_accept_offer_payload(
     offer_id: &[u8; 32],
     src_amount_sd: u64,
     src_buyer_address: &[u8; 32],
-    dst_buyer_address: &[u8; 32]
+    dst_buyer_address: &[u8; 32],
+    src_token_address: &[u8; 32],
+    src_seller_address: &[u8; 32],
+    src_token_program: &[u8; 32]
 ) -> Vec<u8> {
     [
         &(Message::OfferAccepted as u8).to_be_bytes() as &[u8],
@@ -57,6 +145,9 @@ pub fn build_accept_offer_payload(
         &src_amount_sd.to_be_bytes(),
         src_buyer_address,
         dst_buyer_address,
+        src_token_address,
+        src_seller_address,
+        src_token_program,
     ].concat()
 }
 
@@ -68,7 +159,7 @@ pub fn offer_id(message: &[u8]) -> [u8; 32] {
     message[1..33].try_into().unwrap()
 }
 
-pub fn decode_offer_created(message: &[u8], bump: u8) -> Offer {
+pub fn decode_offer_created(message: &[u8], payer: Pubkey, bump: u8) -> Offer {
     Offer {
         src_seller_address: message[33..65].try_into().unwrap(),
         dst_seller_address: message[65..97].try_into().unwrap(),
@@ -78,22 +169,60 @@ pub fn decode_offer_created(message: &[u8], bump: u8) -> Offer {
         dst_token_address: message[137..169].try_into().unwrap(),
         src_amount_sd: u64::from_be_bytes(message[169..177].try_into().unwrap()),
         exchange_rate_sd: u64::from_be_bytes(message[177..185].try_into().unwrap()),
+        expires_at: match i64::from_be_bytes(message[185..193].try_into().unwrap()) {
+            0 => None,
+            expires_at => Some(expires_at),
+        },
+        nonce: u64::from_be_bytes(message[193..201].try_into().unwrap()),
+        min_fill_sd: u64::from_be_bytes(message[201..209].try_into().unwrap()),
+        all_or_nothing: message[209] != 0,
+        allowed_buyer: match <[u8; 32]>::try_from(&message[210..242]).unwrap() {
+            allowed_buyer if allowed_buyer == <[u8; 32]>::default() => None,
+            allowed_buyer => Some(allowed_buyer),
+        },
+        buyers_root: match <[u8; 32]>::try_from(&message[242..274]).unwrap() {
+            buyers_root if buyers_root == <[u8; 32]>::default() => None,
+            buyers_root => Some(buyers_root),
+        },
+        src_token_program: message[274..306].try_into().unwrap(),
+        src_granularity_sd: u64::from_be_bytes(message[306..314].try_into().unwrap()),
+        cancel_pending: false,
+        price_revision: 0,
 
+        payer,
         bump,
     }
 }
 
+/// same layout as offer created, src_amount_sd is the topped up amount
+pub fn decode_offer_topped_up(message: &[u8], payer: Pubkey, bump: u8) -> Offer {
+    decode_offer_created(message, payer, bump)
+}
+
 pub fn src_buyer_address(message: &[u8]) -> [u8; 32] {
     message[41..73].try_into().unwrap()
 }
 
-pub fn decode_offer_accepted(message: &[u8]) -> ([u8; 32], u64, [u8; 32], [u8; 32], [u8; 32]) {
+/// offer_id, src_amount_sd, src_buyer, dst_buyer, src_token, src_seller, src_token_program
+pub type DecodedOfferAccepted = (
+    [u8; 32],
+    u64,
+    [u8; 32],
+    [u8; 32],
+    [u8; 32],
+    [u8; 32],
+    [u8; 32],
+);
+
+pub fn decode_offer_accepted(message: &[u8]) -> DecodedOfferAccepted {
     (
         message[1..33].try_into().unwrap(),
         u64::from_be_bytes(message[33..41].try_into().unwrap()),
         message[41..73].try_into().unwrap(),
         message[73..105].try_into().unwrap(),
         message[105..137].try_into().unwrap(),
+        message[137..169].try_into().unwrap(),
+        message[169..201].try_into().unwrap(),
     )
 }
 
@@ -101,11 +230,32 @@ pub fn decode_offer_cancel_order(message: &[u8]) -> [u8; 32] {
     offer_id(message)
 }
 
-pub fn decode_offer_canceled(message: &[u8]) -> ([u8; 32], [u8; 32], [u8; 32]) {
+/// offer_id, src_seller, src_token, src_token_program
+pub type DecodedOfferCanceled = ([u8; 32], [u8; 32], [u8; 32], [u8; 32]);
+
+pub fn decode_offer_canceled(message: &[u8]) -> DecodedOfferCanceled {
     (
         message[1..33].try_into().unwrap(),
         message[33..65].try_into().unwrap(),
         message[65..97].try_into().unwrap(),
+        message[97..129].try_into().unwrap(),
+    )
+}
+
+pub fn decode_offer_reduce_order(message: &[u8]) -> ([u8; 32], u64) {
+    (offer_id(message), u64::from_be_bytes(message[33..41].try_into().unwrap()))
+}
+
+/// offer canceled layout followed by the reduced amount
+pub fn decode_offer_reduced(message: &[u8]) -> (DecodedOfferCanceled, u64) {
+    (decode_offer_canceled(message), u64::from_be_bytes(message[129..137].try_into().unwrap()))
+}
+
+pub fn decode_offer_repriced(message: &[u8]) -> ([u8; 32], u64, u64) {
+    (
+        offer_id(message),
+        u64::from_be_bytes(message[33..41].try_into().unwrap()),
+        u64::from_be_bytes(message[41..49].try_into().unwrap()),
     )
 }
 
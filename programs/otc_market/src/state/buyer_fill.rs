@@ -0,0 +1,13 @@
+use crate::*;
+
+/// Amount of an allowlisted offer filled by a single dst buyer, see [Offer::buyers_root].
+#[account]
+#[derive(InitSpace)]
+pub struct BuyerFill {
+    pub filled_sd: u64,
+    pub bump: u8,
+}
+
+impl BuyerFill {
+    pub const BUYER_FILL_SEED: &'static [u8; 9] = b"BuyerFill";
+}
@@ -1,11 +1,132 @@
 use crate::*;
+use anchor_spl::{
+    token_2022::spl_token_2022::{
+        self,
+        extension::{
+            transfer_fee::{instruction::harvest_withheld_tokens_to_mint, TransferFeeAmount},
+            BaseStateWithExtensions, StateWithExtensions,
+        },
+    },
+    token_interface::{close_account, CloseAccount, Mint, TokenAccount, TokenInterface},
+};
 
+/// escrow vault of a single offer - holds src sol or owns the src token account
 #[account]
 #[derive(InitSpace)]
 pub struct Escrow {
+    pub token_address: [u8; 32],
     pub bump: u8,
 }
 
 impl Escrow {
     pub const ESCROW_SEED: &'static [u8; 6] = b"Escrow";
+
+    /// closes the vault of a drained offer together with its token account
+    /// NOTICE: rounding dust left in the token account goes to dust_to_ata, rent goes to sol_destination,
+    /// token-2022 transfer fees withheld in the token account are harvested to the (writable) mint
+    pub fn close_vault<'info>(
+        escrow: &Account<'info, Escrow>,
+        offer_id: &[u8; 32],
+        escrow_ledger: &mut EscrowLedger,
+        sol_destination: &AccountInfo<'info>,
+
+        // spl token
+        token_program: Option<&Interface<'info, TokenInterface>>,
+        escrow_ata: Option<&InterfaceAccount<'info, TokenAccount>>,
+        token_mint: Option<&InterfaceAccount<'info, Mint>>,
+        dust_to_ata: Option<&InterfaceAccount<'info, TokenAccount>>,
+    ) -> Result<()> {
+        let seeds: &[&[&[u8]]] = &[&[Self::ESCROW_SEED, offer_id, &[escrow.bump]]];
+
+        if let Some(token_mint) = token_mint {
+            let token_program = token_program.expect(OtcConfig::ERROR_MSG);
+            let escrow_ata_info = escrow_ata.expect(OtcConfig::ERROR_MSG).to_account_info();
+
+            // read from the account data, the deserialized amount is stale after the transfers
+            let (dust_ld, withheld_ld): (u64, u64);
+            {
+                let data = escrow_ata_info.try_borrow_data()?;
+                let account = StateWithExtensions::<spl_token_2022::state::Account>::unpack(&data)?;
+                dust_ld = account.base.amount;
+                withheld_ld = account
+                    .get_extension::<TransferFeeAmount>()
+                    .map_or(0, |transfer_fee_amount| {
+                        u64::from(transfer_fee_amount.withheld_amount)
+                    });
+            }
+
+            if dust_ld != 0 {
+                OtcConfig::transfer(
+                    &escrow.to_account_info(),
+                    dust_ld,
+                    None,
+                    Some(token_program),
+                    escrow_ata,
+                    Some(token_mint),
+                    dust_to_ata,
+                    Some(seeds),
+                )?;
+            }
+
+            if withheld_ld != 0 {
+                solana_program::program::invoke(
+                    &harvest_withheld_tokens_to_mint(
+                        &token_program.key(),
+                        &token_mint.key(),
+                        &[escrow_ata_info.key],
+                    )?,
+                    &[token_mint.to_account_info(), escrow_ata_info.clone()],
+                )?;
+            }
+
+            close_account(
+                CpiContext::new(
+                    token_program.to_account_info(),
+                    CloseAccount {
+                        account: escrow_ata_info,
+                        destination: sol_destination.clone(),
+                        authority: escrow.to_account_info(),
+                    },
+                )
+                .with_signer(seeds),
+            )?;
+        }
+
+        escrow_ledger.vaults = escrow_ledger
+            .vaults
+            .checked_sub(1)
+            .ok_or(OtcError::Overflow)?;
+
+        close(escrow.to_account_info(), sol_destination.clone())
+    }
+}
+
+/// Outstanding escrow liabilities of a single token (default address for SOL) across all offers
+/// created on this chain.
+#[account]
+#[derive(InitSpace)]
+pub struct EscrowLedger {
+    pub token_address: [u8; 32],
+    pub escrowed_ld: u64,
+    /// open escrow vaults, one per offer
+    pub vaults: u64,
+    pub bump: u8,
+}
+
+impl EscrowLedger {
+    pub const ESCROW_LEDGER_SEED: &'static [u8; 12] = b"EscrowLedger";
+
+    pub fn deposit(&mut self, token_address: &[u8; 32], bump: u8, amount_ld: u64) {
+        self.token_address = *token_address;
+        self.bump = bump;
+        self.escrowed_ld += amount_ld;
+    }
+
+    pub fn release(&mut self, amount_ld: u64) -> Result<()> {
+        self.escrowed_ld = self
+            .escrowed_ld
+            .checked_sub(amount_ld)
+            .ok_or(OtcError::Overflow)?;
+        Ok(())
+    }
 }
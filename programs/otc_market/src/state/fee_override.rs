@@ -0,0 +1,25 @@
+use crate::*;
+
+/// Overrides `OtcConfig::taker_fee_bps` for a dst token or an eid. Looked up through
+/// its PDA so a buyer can not skip it, an uninitialized account means no override.
+#[account]
+#[derive(InitSpace)]
+pub struct FeeOverride {
+    pub fee_bps: Option<u16>,
+    pub bump: u8,
+}
+
+impl FeeOverride {
+    pub const TOKEN_FEE_SEED: &'static [u8; 8] = b"TokenFee";
+    pub const EID_FEE_SEED: &'static [u8; 6] = b"EidFee";
+
+    pub fn load(info: &AccountInfo) -> Result<Option<u16>> {
+        if info.owner != &crate::ID || info.data_is_empty() {
+            return Ok(None);
+        }
+        let fee_override: FeeOverride = FeeOverride::try_deserialize(
+            &mut &info.try_borrow_data()?[..]
+        )?;
+        Ok(fee_override.fee_bps)
+    }
+}
@@ -1,17 +1,27 @@
+pub mod buyer_fill;
 pub mod enforced_options;
 pub mod escrow;
+pub mod fee_override;
 pub mod lz_receive_types_accounts;
 pub mod offer;
 pub mod otc;
+pub mod pause;
 pub mod peer;
+pub mod referrer;
+pub mod seller_nonce;
 pub mod treasury;
 pub mod types;
 
+pub use buyer_fill::*;
 pub use enforced_options::*;
 pub use escrow::*;
+pub use fee_override::*;
 pub use lz_receive_types_accounts::*;
 pub use offer::*;
 pub use otc::*;
+pub use pause::*;
 pub use peer::*;
+pub use referrer::*;
+pub use seller_nonce::*;
 pub use treasury::*;
 pub use types::*;
@@ -1,5 +1,5 @@
 use crate::*;
-use anchor_lang::solana_program::keccak::hash;
+use anchor_lang::solana_program::keccak::{ hash, hashv };
 
 #[account]
 #[derive(InitSpace)]
@@ -12,10 +12,48 @@ pub struct Offer {
     pub dst_token_address: [u8; 32],
     pub src_amount_sd: u64,
     pub exchange_rate_sd: u64,
+    pub expires_at: Option<i64>,
+    pub nonce: u64,
+    /// NOTICE: 0 - any fill size, the final remainder can always be filled
+    pub min_fill_sd: u64,
+    /// NOTICE: the offer can only be filled at once
+    pub all_or_nothing: bool,
+    /// NOTICE: dst buyer address, None - anyone can accept the offer
+    pub allowed_buyer: Option<[u8; 32]>,
+    /// NOTICE: merkle root of allowlisted dst buyers, None - anyone can accept the offer
+    pub buyers_root: Option<[u8; 32]>,
+    /// NOTICE: spl token or token-2022 program of the src token, zero for sol and non-solana src tokens
+    pub src_token_program: [u8; 32],
+    /// NOTICE: smallest amount in shared decimals the src mint can represent, fills are multiples of it
+    pub src_granularity_sd: u64,
+    /// NOTICE: src - a cancel order is in flight, the offer can't be topped up anymore
+    pub cancel_pending: bool,
+    /// NOTICE: bumped on every reprice, a dst copy drops reprices older than the one it holds
+    pub price_revision: u64,
 
+    /// NOTICE: local - receives the rent back once the offer is closed
+    pub payer: Pubkey,
     pub bump: u8,
 }
 
+/// Layout of offers created before expiry, nonces and per-offer escrow vaults, see [migrate_offer]
+#[derive(AnchorDeserialize)]
+pub struct LegacyOffer {
+    pub src_seller_address: [u8; 32],
+    pub dst_seller_address: [u8; 32],
+    pub src_eid: u32,
+    pub dst_eid: u32,
+    pub src_token_address: [u8; 32],
+    pub dst_token_address: [u8; 32],
+    pub src_amount_sd: u64,
+    pub exchange_rate_sd: u64,
+    pub bump: u8,
+}
+
+impl LegacyOffer {
+    pub const SIZE: usize = 32 + 32 + 4 + 4 + 32 + 32 + 8 + 8 + 1;
+}
+
 impl Offer {
     pub fn hash_offer(
         src_seller_address: &[u8; 32],
@@ -23,16 +61,21 @@ impl Offer {
         dst_eid: u32,
         src_token_address: &[u8; 32],
         dst_token_address: &[u8; 32],
-        exchange_rate_sd: u64
+        exchange_rate_sd: u64,
+        nonce: u64
     ) -> [u8; 32] {
+        let nonce_bytes = nonce.to_be_bytes();
         hash(
             &[
-                src_seller_address,
+                &src_seller_address[..],
                 &src_eid.to_be_bytes()[..],
                 &dst_eid.to_be_bytes()[..],
                 src_token_address,
                 dst_token_address,
                 &exchange_rate_sd.to_be_bytes()[..],
+                // NOTICE: a repriced offer keeps the id derived from its initial rate
+                // NOTICE: nonce 0 keeps the id of legacy offers created before nonces, see [migrate_offer]
+                if nonce == 0 { &[] } else { &nonce_bytes[..] },
             ].concat()
         ).to_bytes()
     }
@@ -46,7 +89,18 @@ impl Offer {
         self.dst_token_address = offer.dst_token_address;
         self.src_amount_sd = offer.src_amount_sd;
         self.exchange_rate_sd = offer.exchange_rate_sd;
+        self.expires_at = offer.expires_at;
+        self.nonce = offer.nonce;
+        self.min_fill_sd = offer.min_fill_sd;
+        self.all_or_nothing = offer.all_or_nothing;
+        self.allowed_buyer = offer.allowed_buyer;
+        self.buyers_root = offer.buyers_root;
+        self.src_token_program = offer.src_token_program;
+        self.src_granularity_sd = offer.src_granularity_sd;
+        self.cancel_pending = offer.cancel_pending;
+        self.price_revision = offer.price_revision;
 
+        self.payer = offer.payer;
         self.bump = offer.bump;
 
         Offer::hash_offer(
@@ -55,7 +109,156 @@ impl Offer {
             offer.dst_eid,
             &offer.src_token_address,
             &offer.dst_token_address,
-            offer.exchange_rate_sd
+            offer.exchange_rate_sd,
+            offer.nonce
         )
     }
+    /// false for a copy just created by init_if_needed in lz_receive
+    pub fn is_initialized(&self) -> bool {
+        self.payer != Pubkey::default()
+    }
+
+    pub fn is_expired(&self, now: i64) -> bool {
+        matches!(self.expires_at, Some(expires_at) if now >= expires_at)
+    }
+
+    /// the final remainder can always be filled, even below the minimum fill
+    /// NOTICE: a fill the src mint can't represent would short-pay the buyer on the src chain
+    pub fn is_fill_allowed(&self, src_amount_sd: u64) -> bool {
+        src_amount_sd % self.src_granularity_sd.max(1) == 0 &&
+            (src_amount_sd == self.src_amount_sd ||
+                (!self.all_or_nothing && src_amount_sd >= self.min_fill_sd))
+    }
+
+    pub fn is_buyer_allowed(&self, dst_buyer_address: &[u8; 32]) -> bool {
+        self.allowed_buyer.is_none() || self.allowed_buyer == Some(*dst_buyer_address)
+    }
+
+    /// leaf - keccak(dst_buyer_address, max_fill_sd), pairs of nodes are hashed sorted
+    pub fn is_buyer_listed(
+        &self,
+        dst_buyer_address: &[u8; 32],
+        max_fill_sd: u64,
+        proof: &[[u8; 32]]
+    ) -> bool {
+        let Some(buyers_root) = self.buyers_root else {
+            return true;
+        };
+
+        let leaf = hash(&[&dst_buyer_address[..], &max_fill_sd.to_be_bytes()].concat()).to_bytes();
+        let root = proof.iter().fold(leaf, |node, sibling| {
+            if node <= *sibling {
+                hashv(&[&node, sibling]).to_bytes()
+            } else {
+                hashv(&[sibling, &node]).to_bytes()
+            }
+        });
+
+        root == buyers_root
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn leaf(buyer: &[u8; 32], max_fill_sd: u64) -> [u8; 32] {
+        hash(&[&buyer[..], &max_fill_sd.to_be_bytes()].concat()).to_bytes()
+    }
+
+    fn node(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+        if a <= b { hashv(&[a, b]).to_bytes() } else { hashv(&[b, a]).to_bytes() }
+    }
+
+    fn offer(buyers_root: Option<[u8; 32]>) -> Offer {
+        Offer {
+            src_seller_address: [1; 32],
+            dst_seller_address: [2; 32],
+            src_eid: 1,
+            dst_eid: 2,
+            src_token_address: [3; 32],
+            dst_token_address: [4; 32],
+            src_amount_sd: 1_000_000,
+            exchange_rate_sd: 1_000_000,
+            expires_at: None,
+            nonce: 1,
+            min_fill_sd: 0,
+            all_or_nothing: false,
+            allowed_buyer: None,
+            buyers_root,
+            src_token_program: [0; 32],
+            src_granularity_sd: 1,
+            cancel_pending: false,
+            price_revision: 0,
+            payer: Pubkey::default(),
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn fills_above_min_fill() {
+        let mut offer = offer(None);
+        offer.min_fill_sd = 100_000;
+
+        assert!(offer.is_fill_allowed(100_000));
+        assert!(offer.is_fill_allowed(500_000));
+        assert!(!offer.is_fill_allowed(99_999));
+        assert!(!offer.is_fill_allowed(0));
+    }
+
+    #[test]
+    fn final_remainder_below_min_fill() {
+        let mut offer = offer(None);
+        offer.min_fill_sd = 100_000;
+        offer.src_amount_sd = 50_000;
+
+        assert!(offer.is_fill_allowed(50_000));
+        assert!(!offer.is_fill_allowed(49_999));
+    }
+
+    #[test]
+    fn all_or_nothing_fills_whole_amount() {
+        let mut offer = offer(None);
+        offer.all_or_nothing = true;
+
+        assert!(offer.is_fill_allowed(1_000_000));
+        assert!(!offer.is_fill_allowed(999_999));
+        assert!(!offer.is_fill_allowed(1));
+    }
+
+    #[test]
+    fn buyer_listed_with_proof() {
+        let (alice, bob, carol) = ([10u8; 32], [11u8; 32], [12u8; 32]);
+        let (a, b, c) = (leaf(&alice, 0), leaf(&bob, 500), leaf(&carol, 1_000));
+        let ab = node(&a, &b);
+        let offer = offer(Some(node(&ab, &c)));
+
+        assert!(offer.is_buyer_listed(&alice, 0, &[b, c]));
+        assert!(offer.is_buyer_listed(&bob, 500, &[a, c]));
+        assert!(offer.is_buyer_listed(&carol, 1_000, &[ab]));
+
+        // wrong cap, wrong buyer, wrong proof
+        assert!(!offer.is_buyer_listed(&bob, 0, &[a, c]));
+        assert!(!offer.is_buyer_listed(&[13; 32], 0, &[b, c]));
+        assert!(!offer.is_buyer_listed(&alice, 0, &[c]));
+    }
+
+    #[test]
+    fn any_buyer_without_root() {
+        assert!(offer(None).is_buyer_listed(&[10; 32], 0, &[]));
+    }
+
+    #[test]
+    fn fills_in_src_granularity() {
+        let mut offer = offer(None);
+        offer.src_granularity_sd = 10_000; // src mint with 2 decimals
+
+        assert!(offer.is_fill_allowed(10_000));
+        assert!(offer.is_fill_allowed(1_000_000));
+        assert!(!offer.is_fill_allowed(1));
+        assert!(!offer.is_fill_allowed(999_999));
+
+        offer.src_granularity_sd = 1;
+        assert!(offer.is_fill_allowed(1));
+    }
 }
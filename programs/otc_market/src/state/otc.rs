@@ -1,6 +1,14 @@
 use crate::*;
-use anchor_spl::token_interface::{
-    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+use anchor_spl::{
+    token_2022::spl_token_2022::{
+        self,
+        extension::{
+            confidential_transfer::ConfidentialTransferMint, permanent_delegate::PermanentDelegate,
+            transfer_fee::TransferFeeConfig, transfer_hook::TransferHook,
+            BaseStateWithExtensions, StateWithExtensions,
+        },
+    },
+    token_interface::{transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked},
 };
 use oapp::endpoint::{instructions::RegisterOAppParams, ID as ENDPOINT_ID};
 
@@ -10,27 +18,111 @@ pub struct OtcConfig {
     pub endpoint_program: Pubkey,
     pub bump: u8,
 
-    // pub eid: u32,
+    pub eid: u32,
     pub admin: Pubkey,
+    pub pending_admin: Option<Pubkey>,
+
+    // roles
+    pub peer_manager: Pubkey,
+    pub options_manager: Pubkey,
+    pub fee_manager: Pubkey,
+    pub pauser: Pubkey,
+
+    /// fees accrue in the program-owned [Treasury] vault, this wallet is where withdraw_fees pays them out
     pub treasury: Pubkey,
+    pub maker_fee_bps: u16,
+    pub taker_fee_bps: u16,
+
+    pub paused: bool,
+    pub pause_cancellations: bool,
+
+    /// token-2022 extensions allowed on src and dst mints, see ALLOW_* flags
+    pub token_extension_policy: u8,
 }
 
-impl OtcConfig {
+#[derive(Clone, Copy, AnchorSerialize, AnchorDeserialize, PartialEq, Eq)]
+pub enum Role {
+    PeerManager,
+    OptionsManager,
+    FeeManager,
+    Pauser,
+}
+
+/// Layout of the config created before roles, fees, pauses and the stored local eid, see [migrate_config]
+#[derive(AnchorDeserialize)]
+pub struct LegacyOtcConfig {
+    pub endpoint_program: Pubkey,
+    pub bump: u8,
+    pub admin: Pubkey,
+    pub treasury: Pubkey,
+}
+
+impl LegacyOtcConfig {
+    pub const SIZE: usize = 32 + 1 + 32 + 32;
+    // the local eid legacy programs hardcoded, legacy offers were created with it
     pub const EID: u32 = 40168;
+
+    /// all roles start with the admin and the fees keep the legacy hardcoded 1% taker fee
+    pub fn migrate(&self) -> OtcConfig {
+        OtcConfig {
+            endpoint_program: self.endpoint_program,
+            bump: self.bump,
+            eid: Self::EID,
+            admin: self.admin,
+            pending_admin: None,
+            peer_manager: self.admin,
+            options_manager: self.admin,
+            fee_manager: self.admin,
+            pauser: self.admin,
+            treasury: self.treasury,
+            maker_fee_bps: OtcConfig::DEFAULT_MAKER_FEE_BPS,
+            taker_fee_bps: OtcConfig::DEFAULT_TAKER_FEE_BPS,
+            paused: false,
+            pause_cancellations: false,
+            token_extension_policy: 0,
+        }
+    }
+}
+
+impl OtcConfig {
     pub const OTC_SEED: &'static [u8; 3] = b"Otc";
     pub const SHARED_DECIMALS: u8 = 6;
-    pub const FEE: u8 = 100;
+    pub const NATIVE_DECIMALS: u8 = 9;
+    // 10^(decimals - SHARED_DECIMALS) has to fit in u64
+    pub const MAX_LOCAL_DECIMALS: u8 = 25;
+    pub const DEFAULT_MAKER_FEE_BPS: u16 = 0;
+    pub const DEFAULT_TAKER_FEE_BPS: u16 = 100;
+    pub const MAX_FEE_BPS: u16 = 1_000;
+    pub const BPS_DENOMINATOR: u64 = 10_000;
+
+    // token extension policy flags, transfer fees are always accounted for
+    // NOTICE: mints with a transfer hook or a permanent delegate are never allowed -
+    // a hook can block a refund or a fill, a delegate can drain the escrow
+    pub const ALLOW_CONFIDENTIAL_TRANSFER: u8 = 1;
 
     pub const ERROR_MSG: &'static str = "An account required by the instruction is missing";
 
     pub fn init(
         &mut self,
         endpoint_program: Option<Pubkey>,
+        eid: u32,
         admin: Pubkey,
         accounts: &[AccountInfo],
         oapp_signer: Pubkey,
     ) -> Result<()> {
+        self.eid = eid;
         self.admin = admin;
+        self.pending_admin = None;
+
+        // all roles start with the admin
+        self.peer_manager = admin;
+        self.options_manager = admin;
+        self.fee_manager = admin;
+        self.pauser = admin;
+
+        self.maker_fee_bps = Self::DEFAULT_MAKER_FEE_BPS;
+        self.taker_fee_bps = Self::DEFAULT_TAKER_FEE_BPS;
+
         self.endpoint_program = if let Some(endpoint_program) = endpoint_program {
             endpoint_program
         } else {
@@ -49,19 +141,94 @@ impl OtcConfig {
         )
     }
 
-    pub fn ld2sd(amount_ld: u64, decimal_conversion_rate: u64) -> u64 {
-        amount_ld / decimal_conversion_rate
+    pub fn role(&self, role: Role) -> Pubkey {
+        match role {
+            Role::PeerManager => self.peer_manager,
+            Role::OptionsManager => self.options_manager,
+            Role::FeeManager => self.fee_manager,
+            Role::Pauser => self.pauser,
+        }
+    }
+
+    pub fn set_role(&mut self, role: Role, account: Pubkey) {
+        match role {
+            Role::PeerManager => self.peer_manager = account,
+            Role::OptionsManager => self.options_manager = account,
+            Role::FeeManager => self.fee_manager = account,
+            Role::Pauser => self.pauser = account,
+        }
+    }
+
+    /// blocks new offers and accepts (and their messages) when paused globally or for the eid
+    pub fn assert_not_paused(&self, eid_pause: &AccountInfo) -> Result<()> {
+        require!(!self.paused && !EidPause::load(eid_pause)?, OtcError::Paused);
+        Ok(())
+    }
+
+    /// cancellations and refunds stay enabled while paused unless the policy says otherwise
+    pub fn assert_cancellations_not_paused(&self, eid_pause: &AccountInfo) -> Result<()> {
+        if self.pause_cancellations { self.assert_not_paused(eid_pause) } else { Ok(()) }
+    }
+
+    /// token override > eid override > global taker fee
+    pub fn get_taker_fee_bps(
+        &self,
+        token_fee: &AccountInfo,
+        eid_fee: &AccountInfo,
+    ) -> Result<u16> {
+        if let Some(fee_bps) = FeeOverride::load(token_fee)? {
+            return Ok(fee_bps);
+        }
+        if let Some(fee_bps) = FeeOverride::load(eid_fee)? {
+            return Ok(fee_bps);
+        }
+        Ok(self.taker_fee_bps)
+    }
+
+    /// maker fee is taken in src token from the amount the seller escrows, rounded up to the mint granularity
+    pub fn to_maker_fee(src_amount_sd: u64, maker_fee_bps: u16, local_decimals: u8) -> u64 {
+        let granularity_sd = Self::get_granularity_sd(local_decimals) as u128;
+
+        // never exceeds src_amount_sd as maker_fee_bps <= BPS_DENOMINATOR
+        // and src_amount_sd is a multiple of the granularity
+        let maker_fee_sd = Self::div_ceil(
+            (src_amount_sd as u128) * (maker_fee_bps as u128),
+            Self::BPS_DENOMINATOR as u128,
+        );
+        (Self::div_ceil(maker_fee_sd, granularity_sd) * granularity_sd) as u64
+    }
+
+    /// mints with more decimals than SHARED_DECIMALS are scaled down, mints with fewer are scaled up
+    pub fn ld2sd(amount_ld: u64, local_decimals: u8) -> Result<u64> {
+        if local_decimals >= Self::SHARED_DECIMALS {
+            Ok(amount_ld / Self::get_decimal_conversion_rate(local_decimals))
+        } else {
+            amount_ld
+                .checked_mul(Self::get_decimal_conversion_rate(local_decimals))
+                .ok_or(OtcError::Overflow.into())
+        }
     }
 
-    pub fn sd2ld(amount_sd: u64, decimal_conversion_rate: u64) -> u64 {
-        amount_sd * decimal_conversion_rate
+    /// rounds down for mints with fewer decimals than SHARED_DECIMALS
+    pub fn sd2ld(amount_sd: u64, local_decimals: u8) -> u64 {
+        if local_decimals >= Self::SHARED_DECIMALS {
+            amount_sd * Self::get_decimal_conversion_rate(local_decimals)
+        } else {
+            amount_sd / Self::get_decimal_conversion_rate(local_decimals)
+        }
     }
 
-    pub fn remove_dust(amount_ld: u64, decimal_conversion_rate: u64) -> (u64, u64) {
-        let amount_sd = Self::ld2sd(amount_ld, decimal_conversion_rate);
-        let amount_ld = Self::sd2ld(amount_sd, decimal_conversion_rate);
+    pub fn remove_dust(amount_ld: u64, local_decimals: u8) -> Result<(u64, u64)> {
+        let amount_sd = Self::ld2sd(amount_ld, local_decimals)?;
+        let amount_ld = Self::sd2ld(amount_sd, local_decimals);
+
+        Ok((amount_sd, amount_ld))
+    }
 
-        (amount_sd, amount_ld)
+    /// true if amount_sd converts to local decimals without losing precision
+    pub fn is_representable(amount_sd: u64, local_decimals: u8) -> bool {
+        local_decimals >= Self::SHARED_DECIMALS
+            || amount_sd % Self::get_decimal_conversion_rate(local_decimals) == 0
     }
 
     pub fn get_token_address(token_mint: Option<&InterfaceAccount<Mint>>) -> [u8; 32] {
@@ -72,30 +239,199 @@ impl OtcConfig {
         }
     }
 
-    pub fn get_decimal_conversion_rate(token_mint: Option<&InterfaceAccount<Mint>>) -> u64 {
+    /// spl token or token-2022 program owning the mint
+    pub fn get_token_program_address(token_mint: Option<&InterfaceAccount<Mint>>) -> [u8; 32] {
+        if let Some(mint) = token_mint {
+            mint.to_account_info().owner.to_bytes()
+        } else {
+            <[u8; 32]>::default()
+        }
+    }
+
+    /// withheld from the recipient by the transfer fee extension, 0 unless a token-2022 mint
+    pub fn get_transfer_fee(token_mint: Option<&InterfaceAccount<Mint>>, amount: u64) -> Result<u64> {
+        let Some(token_mint) = token_mint else {
+            return Ok(0);
+        };
+        let mint_info = token_mint.to_account_info();
+        if *mint_info.owner != spl_token_2022::ID {
+            return Ok(0);
+        }
+
+        let mint_data = mint_info.try_borrow_data()?;
+        let mint = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&mint_data)?;
+        match mint.get_extension::<TransferFeeConfig>() {
+            Ok(transfer_fee_config) => {
+                Self::get_epoch_transfer_fee(transfer_fee_config, Clock::get()?.epoch, amount)
+            }
+            Err(_) => Ok(0),
+        }
+    }
+
+    /// the older fee applies until the epoch the newer one is scheduled for, rounds up and is capped at maximum_fee
+    pub fn get_epoch_transfer_fee(
+        transfer_fee_config: &TransferFeeConfig,
+        epoch: u64,
+        amount: u64,
+    ) -> Result<u64> {
+        transfer_fee_config.calculate_epoch_fee(epoch, amount).ok_or(OtcError::Overflow.into())
+    }
+
+    /// rejects token-2022 mints with a transfer hook, a permanent delegate or an extension the policy doesn't allow
+    pub fn assert_token_extensions_allowed(
+        &self,
+        token_mint: Option<&InterfaceAccount<Mint>>,
+    ) -> Result<()> {
+        let Some(token_mint) = token_mint else {
+            return Ok(());
+        };
+        let mint_info = token_mint.to_account_info();
+        if *mint_info.owner != spl_token_2022::ID {
+            return Ok(());
+        }
+
+        let mint_data = mint_info.try_borrow_data()?;
+        let mint = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&mint_data)?;
+        let is_allowed = |flag: u8| self.token_extension_policy & flag != 0;
+
+        if let Ok(transfer_hook) = mint.get_extension::<TransferHook>() {
+            require!(
+                Option::<Pubkey>::from(transfer_hook.program_id).is_none(),
+                OtcError::UnsupportedTokenExtension
+            );
+        }
+        if mint.get_extension::<ConfidentialTransferMint>().is_ok() {
+            require!(
+                is_allowed(Self::ALLOW_CONFIDENTIAL_TRANSFER),
+                OtcError::UnsupportedTokenExtension
+            );
+        }
+        if let Ok(permanent_delegate) = mint.get_extension::<PermanentDelegate>() {
+            require!(
+                Option::<Pubkey>::from(permanent_delegate.delegate).is_none(),
+                OtcError::UnsupportedTokenExtension
+            );
+        }
+
+        Ok(())
+    }
+
+    pub fn get_local_decimals(token_mint: Option<&InterfaceAccount<Mint>>) -> u8 {
         if let Some(token_mint) = token_mint {
-            (10u64).pow((token_mint.decimals - Self::SHARED_DECIMALS) as u32)
+            token_mint.decimals
         } else {
-            (10u64).pow((9u8 - Self::SHARED_DECIMALS) as u32)
+            Self::NATIVE_DECIMALS
         }
     }
 
+    /// smallest amount in shared decimals a mint can represent, 1 for mints with at least SHARED_DECIMALS
+    pub fn get_granularity_sd(local_decimals: u8) -> u64 {
+        if local_decimals >= Self::SHARED_DECIMALS {
+            1
+        } else {
+            Self::get_decimal_conversion_rate(local_decimals)
+        }
+    }
+
+    /// 10^|local_decimals - SHARED_DECIMALS|
+    pub fn get_decimal_conversion_rate(local_decimals: u8) -> u64 {
+        (10u64).pow(local_decimals.abs_diff(Self::SHARED_DECIMALS) as u32)
+    }
+
+    fn div_ceil(numerator: u128, denominator: u128) -> u128 {
+        numerator / denominator + u128::from(numerator % denominator > 0)
+    }
+
+    /// src_amount_sd * exchange_rate_sd in dst local decimals, rounded up in favor of the seller
+    pub fn get_dst_amount_ld(
+        src_amount_sd: u64,
+        exchange_rate_sd: u64,
+        dst_local_decimals: u8,
+    ) -> Result<u64> {
+        let dst_amount = (src_amount_sd as u128) * (exchange_rate_sd as u128);
+        let dst_decimal_conversion_rate =
+            Self::get_decimal_conversion_rate(dst_local_decimals) as u128;
+        let shared_decimals_rate = (10u128).pow(Self::SHARED_DECIMALS as u32);
+
+        let dst_amount_ld = if dst_local_decimals >= Self::SHARED_DECIMALS {
+            Self::div_ceil(
+                dst_amount
+                    .checked_mul(dst_decimal_conversion_rate)
+                    .ok_or(OtcError::Overflow)?,
+                shared_decimals_rate,
+            )
+        } else {
+            Self::div_ceil(dst_amount, shared_decimals_rate * dst_decimal_conversion_rate)
+        };
+
+        u64::try_from(dst_amount_ld).map_err(|_| OtcError::Overflow.into())
+    }
+
+    /// taker fee is rounded up in favor of the protocol, referrer share is rounded down
+    pub fn get_fees_ld(
+        dst_amount_ld: u64,
+        taker_fee_bps: u16,
+        referrer_share_bps: u16,
+    ) -> (u64, u64) {
+        // never exceeds dst_amount_ld as taker_fee_bps <= BPS_DENOMINATOR
+        let taker_fee_ld = Self::div_ceil(
+            (dst_amount_ld as u128) * (taker_fee_bps as u128),
+            Self::BPS_DENOMINATOR as u128,
+        ) as u64;
+
+        // referrer share is carved out of the taker fee
+        let referrer_fee_ld = (((taker_fee_ld as u128) * (referrer_share_bps as u128))
+            / (Self::BPS_DENOMINATOR as u128)) as u64;
+
+        (taker_fee_ld, referrer_fee_ld)
+    }
+
     pub fn to_dst_amount(
         src_amount_sd: u64,
         exchange_rate_sd: u64,
         dst_token_mint: Option<&InterfaceAccount<Mint>>,
-    ) -> AcceptOfferReceipt {
-        let dst_decimal_conversion_rate = Self::get_decimal_conversion_rate(dst_token_mint);
+        taker_fee_bps: u16,
+        referrer_share_bps: u16,
+    ) -> Result<AcceptOfferReceipt> {
+        Self::to_dst_amount_with(
+            src_amount_sd,
+            exchange_rate_sd,
+            Self::get_local_decimals(dst_token_mint),
+            taker_fee_bps,
+            referrer_share_bps,
+            |amount_ld| Self::get_transfer_fee(dst_token_mint, amount_ld),
+        )
+    }
 
-        let dst_amount_ld = (src_amount_sd * exchange_rate_sd * dst_decimal_conversion_rate)
-            / (10u64).pow(Self::SHARED_DECIMALS as u32); // TODO: check for overflow
+    /// to_dst_amount for a dst mint of dst_local_decimals, transfer_fee is withheld from each dst transfer
+    pub fn to_dst_amount_with(
+        src_amount_sd: u64,
+        exchange_rate_sd: u64,
+        dst_local_decimals: u8,
+        taker_fee_bps: u16,
+        referrer_share_bps: u16,
+        transfer_fee: impl Fn(u64) -> Result<u64>,
+    ) -> Result<AcceptOfferReceipt> {
+        let dst_amount_ld =
+            Self::get_dst_amount_ld(src_amount_sd, exchange_rate_sd, dst_local_decimals)?;
+
+        let (taker_fee_ld, referrer_fee_ld) =
+            Self::get_fees_ld(dst_amount_ld, taker_fee_bps, referrer_share_bps);
 
-        let fee_ld = dst_amount_ld / (Self::FEE as u64);
+        // withheld from what the seller, the treasury and the referrer receive
+        let seller_transfer_fee_ld = transfer_fee(dst_amount_ld - taker_fee_ld)?;
+        let transfer_fee_ld = seller_transfer_fee_ld
+            + transfer_fee(taker_fee_ld - referrer_fee_ld)?
+            + transfer_fee(referrer_fee_ld)?;
 
-        AcceptOfferReceipt {
+        Ok(AcceptOfferReceipt {
             dst_amount_ld,
-            fee_ld,
-        }
+            taker_fee_ld,
+            taker_fee_bps,
+            referrer_fee_ld,
+            dst_seller_amount_ld: dst_amount_ld - taker_fee_ld - seller_transfer_fee_ld,
+            transfer_fee_ld,
+        })
     }
 
     pub fn transfer<'info>(
@@ -159,3 +495,233 @@ impl OtcConfig {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_spl::token_2022::spl_token_2022::extension::transfer_fee::TransferFee;
+
+    const SD: u64 = 1_000_000; // 1.0 in shared decimals
+
+    #[test]
+    fn legacy_config_migrates() {
+        let (endpoint_program, admin, treasury) =
+            (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+        let data = [endpoint_program.as_ref(), &[254], admin.as_ref(), treasury.as_ref()].concat();
+        assert_eq!(data.len(), LegacyOtcConfig::SIZE);
+
+        let mut migrated = Vec::new();
+        LegacyOtcConfig::deserialize(&mut &data[..])
+            .unwrap()
+            .migrate()
+            .try_serialize(&mut migrated)
+            .unwrap();
+        let config = OtcConfig::try_deserialize(&mut &migrated[..]).unwrap();
+
+        assert_eq!(config.endpoint_program, endpoint_program);
+        assert_eq!(config.bump, 254);
+        assert_eq!(config.eid, 40168);
+        assert_eq!(config.admin, admin);
+        assert_eq!(config.pending_admin, None);
+        assert!(config.role(Role::PeerManager) == admin && config.role(Role::Pauser) == admin);
+        assert_eq!(config.treasury, treasury);
+        assert_eq!((config.maker_fee_bps, config.taker_fee_bps), (0, 100));
+        assert!(!config.paused && !config.pause_cancellations);
+        assert_eq!(config.token_extension_policy, 0);
+    }
+
+    #[test]
+    fn dst_amount_across_decimals() {
+        // 1.0 src at 2.5 dst per src
+        for decimals in [1u8, 2, 4, 6, 8, 9, 12, 18] {
+            assert_eq!(
+                OtcConfig::get_dst_amount_ld(SD, 2 * SD + SD / 2, decimals).unwrap(),
+                25 * (10u64).pow((decimals - 1) as u32)
+            );
+        }
+        assert_eq!(OtcConfig::get_dst_amount_ld(SD, 2 * SD + SD / 2, 0).unwrap(), 3);
+    }
+
+    #[test]
+    fn dst_amount_rounds_up_below_shared_decimals() {
+        // 0.000001 dst rounds up to a whole local unit
+        assert_eq!(OtcConfig::get_dst_amount_ld(1, SD, 0).unwrap(), 1);
+        assert_eq!(OtcConfig::get_dst_amount_ld(1, SD, 4).unwrap(), 1);
+        assert_eq!(OtcConfig::get_dst_amount_ld(100, SD, 4).unwrap(), 1);
+        assert_eq!(OtcConfig::get_dst_amount_ld(101, SD, 4).unwrap(), 2);
+        assert!(OtcConfig::get_dst_amount_ld(u64::MAX, u64::MAX, 0).is_err());
+    }
+
+    #[test]
+    fn conversion_below_shared_decimals() {
+        assert_eq!(OtcConfig::ld2sd(1, 0).unwrap(), SD);
+        assert_eq!(OtcConfig::ld2sd(123, 2).unwrap(), 1_230_000);
+        assert_eq!(OtcConfig::sd2ld(1_230_000, 2), 123);
+        assert_eq!(OtcConfig::sd2ld(1_239_999, 2), 123);
+        assert!(OtcConfig::ld2sd(u64::MAX, 4).is_err());
+        assert_eq!(OtcConfig::remove_dust(42, 3).unwrap(), (42_000, 42));
+
+        assert!(OtcConfig::is_representable(1_230_000, 2));
+        assert!(!OtcConfig::is_representable(1_239_999, 2));
+        assert!(OtcConfig::is_representable(1, 6));
+        assert!(OtcConfig::is_representable(1, 18));
+    }
+
+    #[test]
+    fn conversion_above_shared_decimals() {
+        assert_eq!(OtcConfig::ld2sd(1_999_999_999, 9).unwrap(), 1_999_999);
+        assert_eq!(OtcConfig::sd2ld(1_999_999, 9), 1_999_999_000);
+        assert_eq!(OtcConfig::remove_dust(1_999_999_999, 9).unwrap(), (1_999_999, 1_999_999_000));
+        assert_eq!(OtcConfig::remove_dust(u64::MAX, 6).unwrap(), (u64::MAX, u64::MAX));
+        assert_eq!(
+            OtcConfig::get_decimal_conversion_rate(OtcConfig::MAX_LOCAL_DECIMALS),
+            10_000_000_000_000_000_000
+        );
+    }
+
+    #[test]
+    fn dst_amount_large_fill_of_18_decimal_token() {
+        // 10_000 src at 1_000 dst per src is 1e25 in 18 decimals
+        assert!(OtcConfig::get_dst_amount_ld(10_000 * SD, 1_000 * SD, 18).is_err());
+
+        // 10 src at 1_000 dst per src is 1e22 in 18 decimals, u64 tops at ~1.8e19
+        assert!(OtcConfig::get_dst_amount_ld(10 * SD, 1_000 * SD, 18).is_err());
+
+        // 10 src at 1 dst per src fits
+        assert_eq!(
+            OtcConfig::get_dst_amount_ld(10 * SD, SD, 18).unwrap(),
+            10_000_000_000_000_000_000
+        );
+    }
+
+    #[test]
+    fn dst_amount_extremes() {
+        assert_eq!(OtcConfig::get_dst_amount_ld(u64::MAX, SD, 6).unwrap(), u64::MAX);
+        assert_eq!(OtcConfig::get_dst_amount_ld(SD, u64::MAX, 6).unwrap(), u64::MAX);
+        assert!(OtcConfig::get_dst_amount_ld(u64::MAX, SD + 1, 6).is_err());
+        assert!(OtcConfig::get_dst_amount_ld(u64::MAX, u64::MAX, 6).is_err());
+        assert!(OtcConfig::get_dst_amount_ld(u64::MAX, u64::MAX, 18).is_err());
+        assert_eq!(OtcConfig::get_dst_amount_ld(0, u64::MAX, 18).unwrap(), 0);
+        assert_eq!(OtcConfig::get_dst_amount_ld(u64::MAX, 0, 18).unwrap(), 0);
+    }
+
+    #[test]
+    fn dst_amount_rounds_up_for_seller() {
+        // 0.000001 src at 0.000001 dst per src is 1e-12 dst
+        assert_eq!(OtcConfig::get_dst_amount_ld(1, 1, 6).unwrap(), 1);
+        assert_eq!(OtcConfig::get_dst_amount_ld(1, 1, 9).unwrap(), 1);
+        assert_eq!(OtcConfig::get_dst_amount_ld(1, 1, 18).unwrap(), 1_000_000);
+        assert_eq!(OtcConfig::get_dst_amount_ld(3, SD / 2, 6).unwrap(), 2);
+        assert_eq!(OtcConfig::get_dst_amount_ld(4, SD / 2, 6).unwrap(), 2);
+    }
+
+    #[test]
+    fn fees_round_in_favor_of_protocol() {
+        assert_eq!(OtcConfig::get_fees_ld(1, 1, 0), (1, 0));
+        assert_eq!(OtcConfig::get_fees_ld(10_000, 100, 0), (100, 0));
+        assert_eq!(OtcConfig::get_fees_ld(10_001, 100, 0), (101, 0));
+        assert_eq!(OtcConfig::get_fees_ld(0, 100, 0), (0, 0));
+        assert_eq!(OtcConfig::get_fees_ld(u64::MAX, 10_000, 0), (u64::MAX, 0));
+
+        // referrer share rounds down
+        assert_eq!(OtcConfig::get_fees_ld(10_001, 100, 5_000), (101, 50));
+        assert_eq!(OtcConfig::get_fees_ld(u64::MAX, 10_000, 10_000), (u64::MAX, u64::MAX));
+    }
+
+    fn transfer_fee(epoch: u64, maximum_fee: u64, transfer_fee_basis_points: u16) -> TransferFee {
+        TransferFee {
+            epoch: epoch.into(),
+            maximum_fee: maximum_fee.into(),
+            transfer_fee_basis_points: transfer_fee_basis_points.into(),
+        }
+    }
+
+    fn transfer_fee_config(older: TransferFee, newer: TransferFee) -> TransferFeeConfig {
+        TransferFeeConfig {
+            older_transfer_fee: older,
+            newer_transfer_fee: newer,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn transfer_fee_rounds_up_and_is_capped() {
+        // 1% capped at 5_000
+        let config = transfer_fee_config(transfer_fee(0, 5_000, 100), transfer_fee(0, 5_000, 100));
+
+        assert_eq!(OtcConfig::get_epoch_transfer_fee(&config, 0, 0).unwrap(), 0);
+        assert_eq!(OtcConfig::get_epoch_transfer_fee(&config, 0, 1).unwrap(), 1);
+        assert_eq!(OtcConfig::get_epoch_transfer_fee(&config, 0, 10_000).unwrap(), 100);
+        assert_eq!(OtcConfig::get_epoch_transfer_fee(&config, 0, 10_001).unwrap(), 101);
+        assert_eq!(OtcConfig::get_epoch_transfer_fee(&config, 0, 500_000).unwrap(), 5_000);
+        assert_eq!(OtcConfig::get_epoch_transfer_fee(&config, 0, u64::MAX).unwrap(), 5_000);
+
+        let config = transfer_fee_config(transfer_fee(0, 0, 0), transfer_fee(0, 0, 0));
+        assert_eq!(OtcConfig::get_epoch_transfer_fee(&config, 0, SD).unwrap(), 0);
+    }
+
+    #[test]
+    fn transfer_fee_switches_at_newer_epoch() {
+        // 1% until epoch 10, 2% from then on
+        let config = transfer_fee_config(
+            transfer_fee(0, u64::MAX, 100),
+            transfer_fee(10, u64::MAX, 200),
+        );
+
+        assert_eq!(OtcConfig::get_epoch_transfer_fee(&config, 9, SD).unwrap(), SD / 100);
+        assert_eq!(OtcConfig::get_epoch_transfer_fee(&config, 10, SD).unwrap(), SD / 50);
+        assert_eq!(OtcConfig::get_epoch_transfer_fee(&config, 11, SD).unwrap(), SD / 50);
+    }
+
+    #[test]
+    fn dst_amount_withholds_transfer_fee_per_transfer() {
+        // 1% transfer fee, 1% taker fee with half of it to the referrer
+        let config = transfer_fee_config(
+            transfer_fee(0, u64::MAX, 100),
+            transfer_fee(0, u64::MAX, 100),
+        );
+        let receipt = OtcConfig::to_dst_amount_with(SD, SD, 6, 100, 5_000, |amount_ld| {
+            OtcConfig::get_epoch_transfer_fee(&config, 0, amount_ld)
+        })
+        .unwrap();
+
+        assert_eq!(receipt.dst_amount_ld, SD);
+        assert_eq!((receipt.taker_fee_ld, receipt.referrer_fee_ld), (10_000, 5_000));
+        // seller: 990_000 - 9_900, treasury: 5_000 - 50, referrer: 5_000 - 50
+        assert_eq!(receipt.dst_seller_amount_ld, 980_100);
+        assert_eq!(receipt.transfer_fee_ld, 9_900 + 50 + 50);
+
+        // each transfer rounds up on its own
+        let receipt = OtcConfig::to_dst_amount_with(101, SD, 6, 100, 5_000, |amount_ld| {
+            OtcConfig::get_epoch_transfer_fee(&config, 0, amount_ld)
+        })
+        .unwrap();
+
+        assert_eq!((receipt.taker_fee_ld, receipt.referrer_fee_ld), (2, 1));
+        assert_eq!(receipt.dst_seller_amount_ld, 99 - 1);
+        assert_eq!(receipt.transfer_fee_ld, 3);
+    }
+
+    #[test]
+    fn dst_amount_without_transfer_fee() {
+        let receipt = OtcConfig::to_dst_amount_with(SD, SD, 6, 100, 0, |_| Ok(0)).unwrap();
+
+        assert_eq!(receipt.dst_seller_amount_ld, SD - SD / 100);
+        assert_eq!(receipt.transfer_fee_ld, 0);
+    }
+
+    #[test]
+    fn maker_fee_rounds_up() {
+        assert_eq!(OtcConfig::to_maker_fee(1, 1, 6), 1);
+        assert_eq!(OtcConfig::to_maker_fee(SD, 100, 6), SD / 100);
+        assert_eq!(OtcConfig::to_maker_fee(SD + 1, 100, 6), SD / 100 + 1);
+        assert_eq!(OtcConfig::to_maker_fee(u64::MAX, 10_000, 18), u64::MAX);
+        assert_eq!(OtcConfig::to_maker_fee(u64::MAX, 0, 6), 0);
+
+        // up to the granularity of mints with fewer decimals than SHARED_DECIMALS
+        assert_eq!(OtcConfig::to_maker_fee(SD, 1, 2), 10_000);
+        assert_eq!(OtcConfig::to_maker_fee(SD + 10_000, 100, 2), SD / 100 + 10_000);
+        assert_eq!(OtcConfig::to_maker_fee(10_000, 10_000, 2), 10_000);
+        assert_eq!(OtcConfig::to_maker_fee(10_000, 0, 2), 0);
+    }
+}
@@ -0,0 +1,22 @@
+use crate::*;
+
+/// Pause switch for a single eid. Looked up through its PDA so callers can not
+/// skip it, an uninitialized account means not paused.
+#[account]
+#[derive(InitSpace)]
+pub struct EidPause {
+    pub paused: bool,
+    pub bump: u8,
+}
+
+impl EidPause {
+    pub const PAUSE_SEED: &'static [u8; 5] = b"Pause";
+
+    pub fn load(info: &AccountInfo) -> Result<bool> {
+        if info.owner != &crate::ID || info.data_is_empty() {
+            return Ok(false);
+        }
+        let eid_pause: EidPause = EidPause::try_deserialize(&mut &info.try_borrow_data()?[..])?;
+        Ok(eid_pause.paused)
+    }
+}
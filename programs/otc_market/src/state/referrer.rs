@@ -0,0 +1,13 @@
+use crate::*;
+
+/// Per-referrer cap on the share of the taker fee a front-end can claim.
+#[account]
+#[derive(InitSpace)]
+pub struct Referrer {
+    pub max_share_bps: u16,
+    pub bump: u8,
+}
+
+impl Referrer {
+    pub const REFERRER_SEED: &'static [u8; 8] = b"Referrer";
+}
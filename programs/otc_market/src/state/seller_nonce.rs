@@ -0,0 +1,18 @@
+use crate::*;
+
+/// Per-seller counter of created offers, mixed into the offer id.
+#[account]
+#[derive(InitSpace)]
+pub struct SellerNonce {
+    pub nonce: u64,
+    pub bump: u8,
+}
+
+impl SellerNonce {
+    pub const SELLER_NONCE_SEED: &'static [u8; 11] = b"SellerNonce";
+
+    /// offers are numbered from 1, nonce 0 is reserved for the legacy offer id
+    pub fn next(&self) -> u64 {
+        self.nonce + 1
+    }
+}
@@ -1,5 +1,7 @@
 use crate::*;
 
+/// Program-owned fee vault. Holds SOL fees directly and is the authority of the
+/// per-mint treasury ATAs.
 #[account]
 #[derive(InitSpace)]
 pub struct Treasury {
@@ -9,3 +11,23 @@ pub struct Treasury {
 impl Treasury {
     pub const TREASURY_SEED: &'static [u8; 8] = b"Treasury";
 }
+
+/// Cumulative fee accounting for a single token (default address for SOL).
+#[account]
+#[derive(InitSpace)]
+pub struct FeeLedger {
+    pub token_address: [u8; 32],
+    pub collected_ld: u64,
+    pub withdrawn_ld: u64,
+    pub bump: u8,
+}
+
+impl FeeLedger {
+    pub const FEE_LEDGER_SEED: &'static [u8; 9] = b"FeeLedger";
+
+    pub fn collect(&mut self, token_address: &[u8; 32], bump: u8, amount_ld: u64) {
+        self.token_address = *token_address;
+        self.bump = bump;
+        self.collected_ld += amount_ld;
+    }
+}
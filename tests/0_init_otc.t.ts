@@ -20,11 +20,11 @@ describe("Initialization", () => {
   const wallet = provider.wallet as Wallet;
 
   it("should init otc market", async () => {
-    const { otcConfig, escrow, treasury } = await generateAccounts(
+    const { otcConfig, treasury } = await generateAccounts(
       connection,
       program.programId,
       wallet.payer,
     );
-    await initOtc(program, otcConfig, escrow, treasury, wallet.payer);
+    await initOtc(program, otcConfig, treasury, wallet.payer);
   });
 });
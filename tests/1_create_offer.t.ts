@@ -80,6 +80,12 @@ describe("CreateOffer", () => {
       dstTokenAddress: Array.from(accounts.dstToken.toBytes()),
       srcAmountLd: new anchor.BN(CREATE_OFFER_AMOUNTS.srcAmountLdSpl),
       exchangeRateSd: new anchor.BN(EXCHANGE_RATE_SD),
+      expiresAt: null,
+      nonce: new anchor.BN(1),
+      minFillSd: new anchor.BN(0),
+      allOrNothing: false,
+      allowedBuyer: null,
+      buyersRoot: null,
     };
 
     ({ account: offerAccount } = await getOfferAccount(
@@ -90,6 +96,7 @@ describe("CreateOffer", () => {
       Array.from(accounts.srcToken.toBytes()),
       createOfferParams.dstTokenAddress,
       createOfferParams.exchangeRateSd,
+      createOfferParams.nonce,
     ));
   });
 
@@ -98,7 +105,7 @@ describe("CreateOffer", () => {
       connection,
       wallet.payer,
       accounts.srcSeller.publicKey,
-      4,
+      26,
     );
 
     try {
@@ -128,6 +135,7 @@ describe("CreateOffer", () => {
         Array.from(accounts.srcToken.toBytes()),
         Array.from(accounts.dstToken.toBytes()),
         new anchor.BN(EXCHANGE_RATE_SD),
+        new anchor.BN(1),
       )
       .view();
 
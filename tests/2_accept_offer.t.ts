@@ -2,7 +2,7 @@ import * as anchor from "@coral-xyz/anchor";
 import { expect, assert } from "chai";
 
 import { PublicKey, Keypair } from "@solana/web3.js";
-import { Program, AnchorError, Wallet } from "@coral-xyz/anchor";
+import { BN, Program, AnchorError, Wallet } from "@coral-xyz/anchor";
 import { OtcMarket } from "../target/types/otc_market";
 
 import { createMintAndAta, getBalance } from "./helpers/spl";
@@ -21,7 +21,12 @@ import {
   createSplOffer,
   getOfferAccount,
 } from "./helpers/create_offer";
-import { AcceptOfferParams } from "./helpers/accept_offer";
+import {
+  AcceptOfferParams,
+  getAcceptOfferAccounts,
+  getAcceptOfferParams,
+  getQuoteAcceptOfferAccounts,
+} from "./helpers/accept_offer";
 import { initOtc } from "./helpers/init";
 import {
   ACCEPT_OFFER_AMOUNTS,
@@ -33,6 +38,7 @@ import {
 } from "./helpers/constants";
 import {
   createMint,
+  getAssociatedTokenAddressSync,
   getOrCreateAssociatedTokenAccount,
   mintTo,
 } from "@solana/spl-token";
@@ -48,13 +54,19 @@ describe("AcceptOffer", () => {
   let accounts: Accounts;
   let nativeOffer: PublicKey;
   let nativeOfferId: number[];
+  let nativeEscrow: PublicKey;
   let srcSellerAddress: number[];
   let acceptNativeOfferParams: AcceptOfferParams;
 
   let splOfferId: number[];
   let splOffer: PublicKey;
+  let splEscrow: PublicKey;
+  let splEscrowAta: PublicKey;
   let acceptSplOfferParams: AcceptOfferParams;
 
+  // zero fee, the offers are monochain
+  const fee = { nativeFee: new BN(0), lzTokenFee: new BN(0) };
+
   before(async () => {
     accounts = await generateAccounts(
       connection,
@@ -71,49 +83,57 @@ describe("AcceptOffer", () => {
     );
     srcSellerAddress = Array.from(wallet.publicKey.toBytes());
 
-    const { account: _nativeOffer, id: _nativeOfferId } =
-      await createNativeOffer(program, connection, wallet.payer, accounts);
+    const {
+      account: _nativeOffer,
+      id: _nativeOfferId,
+      escrow: _nativeEscrow,
+    } = await createNativeOffer(program, connection, wallet.payer, accounts);
     nativeOfferId = Array.from(_nativeOfferId);
     nativeOffer = _nativeOffer;
+    nativeEscrow = _nativeEscrow;
 
-    const { account: _splOffer, id: _splOfferId } = await createSplOffer(
-      program,
-      connection,
-      wallet.payer,
-      accounts,
-    );
+    const {
+      account: _splOffer,
+      id: _splOfferId,
+      escrow: _splEscrow,
+    } = await createSplOffer(program, connection, wallet.payer, accounts);
     splOfferId = Array.from(_splOfferId);
     splOffer = _splOffer;
+    splEscrow = _splEscrow;
+    splEscrowAta = getAssociatedTokenAddressSync(
+      accounts.srcToken,
+      splEscrow,
+      true,
+    );
 
-    acceptNativeOfferParams = {
-      offerId: nativeOfferId,
-      srcAmountSd: new anchor.BN(ACCEPT_OFFER_AMOUNTS.srcAmountSd),
-      srcBuyerAddress: Array.from(accounts.srcBuyer.publicKey.toBytes()),
-    };
-    acceptSplOfferParams = {
-      offerId: splOfferId,
-      srcAmountSd: new anchor.BN(ACCEPT_OFFER_AMOUNTS.srcAmountSd),
-      srcBuyerAddress: Array.from(accounts.srcBuyer.publicKey.toBytes()),
-    };
+    acceptNativeOfferParams = getAcceptOfferParams(
+      nativeOfferId,
+      new anchor.BN(ACCEPT_OFFER_AMOUNTS.srcAmountSd),
+      Array.from(accounts.srcBuyer.publicKey.toBytes()),
+    );
+    acceptSplOfferParams = getAcceptOfferParams(
+      splOfferId,
+      new anchor.BN(ACCEPT_OFFER_AMOUNTS.srcAmountSd),
+      Array.from(accounts.srcBuyer.publicKey.toBytes()),
+    );
   });
 
   it("should revert on Exesive amount", async () => {
-    const params: AcceptOfferParams = {
-      offerId: splOfferId,
-      srcAmountSd: new anchor.BN(ACCEPT_OFFER_AMOUNTS.srcAmountSd * 10),
-      srcBuyerAddress: Array.from(accounts.srcBuyerAta.toBytes()),
-    };
+    const params = getAcceptOfferParams(
+      splOfferId,
+      new anchor.BN(ACCEPT_OFFER_AMOUNTS.srcAmountSd * 10),
+      Array.from(accounts.srcBuyerAta.toBytes()),
+    );
     try {
       await program.methods
         .quoteAcceptOffer(
           Array.from(accounts.dstBuyer.publicKey.toBytes()),
           params,
+          false,
+        )
+        .accounts(
+          await getQuoteAcceptOfferAccounts(program, accounts, splOffer),
         )
-        .accounts({
-          otcConfig: accounts.otcConfig,
-          offer: splOffer,
-          dstTokenMint: accounts.dstToken,
-        })
         .view();
 
       assert(false, "should revert");
@@ -129,7 +149,7 @@ describe("AcceptOffer", () => {
       connection,
       wallet.payer,
       accounts.srcSeller.publicKey,
-      4,
+      26,
     );
     const offerInfo = await createOffer(
       program,
@@ -147,28 +167,31 @@ describe("AcceptOffer", () => {
         exchangeRateSd: new anchor.BN(EXCHANGE_RATE_SD),
       },
     );
-    const params: AcceptOfferParams = {
-      offerId: Array.from(offerInfo.id),
-      srcAmountSd: new anchor.BN(ACCEPT_OFFER_AMOUNTS.srcAmountSd),
-      srcBuyerAddress: Array.from(accounts.srcBuyer.publicKey.toBytes()),
-    };
+    const params = getAcceptOfferParams(
+      Array.from(offerInfo.id),
+      new anchor.BN(ACCEPT_OFFER_AMOUNTS.srcAmountSd),
+      Array.from(accounts.srcBuyer.publicKey.toBytes()),
+    );
     try {
       await program.methods
         .quoteAcceptOffer(
           Array.from(accounts.dstBuyer.publicKey.toBytes()),
           params,
+          false,
+        )
+        .accounts(
+          await getQuoteAcceptOfferAccounts(
+            program,
+            accounts,
+            offerInfo.account,
+          ),
         )
-        .accounts({
-          otcConfig: accounts.otcConfig,
-          offer: offerInfo.account,
-          dstTokenMint: dstToken,
-        })
         .view();
 
       assert(false, "should revert");
     } catch (error: any) {
       expect(error.simulationResponse.logs).to.include(
-        "Program log: AnchorError caused by account: dst_token_mint. Error Code: InvalidLocalDecimals. Error Number: 6000. Error Message: InvalidLocalDecimals.",
+        "Program log: AnchorError caused by account: dst_token_mint. Error Code: InvalidLocalDecimals. Error Number: 6001. Error Message: InvalidLocalDecimals.",
       );
     }
   });
@@ -180,21 +203,15 @@ describe("AcceptOffer", () => {
       try {
         // accept native offer
         await program.methods
-          .acceptOffer(acceptNativeOfferParams)
+          .acceptOffer(acceptNativeOfferParams, fee)
           .accounts({
-            buyer: accounts.dstBuyer.publicKey,
-            otcConfig: accounts.otcConfig,
-            offer: nativeOffer,
-            dstBuyerAta: null,
-            dstSellerAta: null,
+            ...(await getAcceptOfferAccounts(
+              program,
+              accounts,
+              nativeOffer,
+              acceptNativeOfferParams,
+            )),
             dstSeller: invalidDstSeller,
-            dstTreasuryAta: null,
-            treasury: accounts.treasury,
-            dstTokenMint: null,
-            srcBuyerAta: null,
-            escrow: accounts.escrow,
-            srcEscrowAta: null,
-            srcTokenMint: null,
           })
           .signers([accounts.dstBuyer])
           .rpc();
@@ -216,21 +233,15 @@ describe("AcceptOffer", () => {
       try {
         // accept native offer
         await program.methods
-          .acceptOffer(acceptNativeOfferParams)
+          .acceptOffer(acceptNativeOfferParams, fee)
           .accounts({
-            buyer: accounts.dstBuyer.publicKey,
-            otcConfig: accounts.otcConfig,
+            ...(await getAcceptOfferAccounts(
+              program,
+              accounts,
+              nativeOffer,
+              acceptNativeOfferParams,
+            )),
             offer: invalidOffer,
-            dstBuyerAta: null,
-            dstSellerAta: null,
-            dstSeller: accounts.dstSeller.publicKey,
-            dstTreasuryAta: null,
-            treasury: accounts.treasury,
-            dstTokenMint: null,
-            srcBuyerAta: null,
-            escrow: accounts.escrow,
-            srcEscrowAta: null,
-            srcTokenMint: null,
           })
           .signers([accounts.dstBuyer])
           .rpc();
@@ -245,42 +256,6 @@ describe("AcceptOffer", () => {
     }
   });
 
-  it("should revert on InvalidTreasury", async () => {
-    const invalidTreasuries: PublicKey[] = [Keypair.generate().publicKey];
-
-    for (const invalidTreasury of invalidTreasuries) {
-      try {
-        // accept native offer
-        await program.methods
-          .acceptOffer(acceptNativeOfferParams)
-          .accounts({
-            buyer: accounts.dstBuyer.publicKey,
-            otcConfig: accounts.otcConfig,
-            offer: nativeOffer,
-            dstBuyerAta: null,
-            dstSellerAta: null,
-            dstSeller: accounts.dstSeller.publicKey,
-            dstTreasuryAta: null,
-            treasury: invalidTreasury,
-            dstTokenMint: null,
-            srcBuyerAta: null,
-            escrow: accounts.escrow,
-            srcEscrowAta: null,
-            srcTokenMint: null,
-          })
-          .signers([accounts.dstBuyer])
-          .rpc();
-
-        assert(false, "should revert");
-      } catch (error: any) {
-        expect(error).to.be.instanceOf(AnchorError);
-        expect((error as AnchorError).error.errorCode.code).to.equal(
-          "InvalidTreasury",
-        );
-      }
-    }
-  });
-
   it("should revert on InvalidDstTokenMint", async () => {
     const invalidDstToken = await createMint(
       connection,
@@ -307,28 +282,26 @@ describe("AcceptOffer", () => {
         connection,
         wallet.payer,
         invalidDstToken,
-        accounts.treasury,
+        accounts.treasuryVault,
+        true,
       ),
     ]);
 
     try {
       // accept native offer
       await program.methods
-        .acceptOffer(acceptSplOfferParams)
+        .acceptOffer(acceptSplOfferParams, fee)
         .accounts({
-          buyer: accounts.dstBuyer.publicKey,
-          otcConfig: accounts.otcConfig,
-          offer: splOffer,
+          ...(await getAcceptOfferAccounts(
+            program,
+            accounts,
+            splOffer,
+            acceptSplOfferParams,
+          )),
           dstBuyerAta: invalidDstAtas[0].address,
           dstSellerAta: invalidDstAtas[1].address,
-          dstSeller: accounts.dstSeller.publicKey,
           dstTreasuryAta: invalidDstAtas[2].address,
-          treasury: accounts.treasury,
           dstTokenMint: invalidDstToken,
-          srcBuyerAta: accounts.srcBuyerAta,
-          escrow: accounts.escrow,
-          srcEscrowAta: accounts.srcEscrowAta,
-          srcTokenMint: accounts.srcToken,
         })
         .signers([accounts.dstBuyer])
         .rpc();
@@ -362,26 +335,22 @@ describe("AcceptOffer", () => {
         connection,
         wallet.payer,
         invalidSrcToken,
-        accounts.escrow,
+        splEscrow,
         true,
       ),
     ]);
 
     try {
       await program.methods
-        .acceptOffer(acceptSplOfferParams)
+        .acceptOffer(acceptSplOfferParams, fee)
         .accounts({
-          buyer: accounts.dstBuyer.publicKey,
-          otcConfig: accounts.otcConfig,
-          offer: splOffer,
-          dstBuyerAta: accounts.dstBuyerAta,
-          dstSellerAta: accounts.dstSellerAta,
-          dstSeller: accounts.dstSeller.publicKey,
-          dstTreasuryAta: accounts.dstTreasuryAta,
-          treasury: accounts.treasury,
-          dstTokenMint: accounts.dstToken,
+          ...(await getAcceptOfferAccounts(
+            program,
+            accounts,
+            splOffer,
+            acceptSplOfferParams,
+          )),
           srcBuyerAta: invalidSrcAtas[0].address,
-          escrow: accounts.escrow,
           srcEscrowAta: invalidSrcAtas[1].address,
           srcTokenMint: invalidSrcToken,
         })
@@ -407,10 +376,7 @@ describe("AcceptOffer", () => {
       ACCEPT_OFFER_AMOUNTS.dstAmountLdSpl,
     );
 
-    const initialEscrowSrcBalance = await getBalance(
-      connection,
-      accounts.srcEscrowAta,
-    );
+    const initialEscrowSrcBalance = await getBalance(connection, splEscrowAta);
     const initialBuyerDstBalance = await getBalance(
       connection,
       accounts.dstBuyerAta,
@@ -430,29 +396,19 @@ describe("AcceptOffer", () => {
     );
 
     await program.methods
-      .acceptOffer(acceptSplOfferParams)
-      .accounts({
-        buyer: accounts.dstBuyer.publicKey,
-        otcConfig: accounts.otcConfig,
-        offer: splOffer,
-        dstBuyerAta: accounts.dstBuyerAta,
-        dstSellerAta: accounts.dstSellerAta,
-        dstSeller: accounts.dstSeller.publicKey,
-        dstTreasuryAta: accounts.dstTreasuryAta,
-        treasury: accounts.treasury,
-        dstTokenMint: accounts.dstToken,
-        srcBuyerAta: accounts.srcBuyerAta,
-        escrow: accounts.escrow,
-        srcEscrowAta: accounts.srcEscrowAta,
-        srcTokenMint: accounts.srcToken,
-      })
+      .acceptOffer(acceptSplOfferParams, fee)
+      .accounts(
+        await getAcceptOfferAccounts(
+          program,
+          accounts,
+          splOffer,
+          acceptSplOfferParams,
+        ),
+      )
       .signers([accounts.dstBuyer])
       .rpc();
 
-    const escrowSrcBalance = await getBalance(
-      connection,
-      accounts.srcEscrowAta,
-    );
+    const escrowSrcBalance = await getBalance(connection, splEscrowAta);
 
     const buyerDstBalance = await getBalance(connection, accounts.dstBuyerAta);
     const sellerDstBalance = await getBalance(
@@ -517,15 +473,13 @@ describe("AcceptOffer", () => {
       ACCEPT_OFFER_AMOUNTS.dstAmountLdNative,
     );
 
-    const initialEscrowSrcBalance = await connection.getBalance(
-      accounts.escrow,
-    );
+    const initialEscrowSrcBalance = await connection.getBalance(nativeEscrow);
 
     const initialBuyerDstBalance = await connection.getBalance(
       accounts.dstBuyer.publicKey,
     );
     const initialTreasuryDstBalance = await connection.getBalance(
-      accounts.treasury,
+      accounts.treasuryVault,
     );
 
     const initialSellerDstBalance = await connection.getBalance(
@@ -533,26 +487,19 @@ describe("AcceptOffer", () => {
     );
 
     await program.methods
-      .acceptOffer(acceptNativeOfferParams)
-      .accounts({
-        buyer: accounts.dstBuyer.publicKey,
-        otcConfig: accounts.otcConfig,
-        offer: nativeOffer,
-        dstBuyerAta: null,
-        dstSellerAta: null,
-        dstSeller: accounts.dstSeller.publicKey,
-        dstTreasuryAta: null,
-        treasury: accounts.treasury,
-        dstTokenMint: null,
-        srcBuyerAta: null,
-        escrow: accounts.escrow,
-        srcEscrowAta: null,
-        srcTokenMint: null,
-      })
+      .acceptOffer(acceptNativeOfferParams, fee)
+      .accounts(
+        await getAcceptOfferAccounts(
+          program,
+          accounts,
+          nativeOffer,
+          acceptNativeOfferParams,
+        ),
+      )
       .signers([accounts.dstBuyer])
       .rpc();
 
-    const escrowSrcBalance = await connection.getBalance(accounts.escrow);
+    const escrowSrcBalance = await connection.getBalance(nativeEscrow);
 
     const buyerDstBalance = await connection.getBalance(
       accounts.dstBuyer.publicKey,
@@ -560,7 +507,9 @@ describe("AcceptOffer", () => {
     const sellerDstBalance = await connection.getBalance(
       accounts.dstSeller.publicKey,
     );
-    const treasuryDstBalance = await connection.getBalance(accounts.treasury);
+    const treasuryDstBalance = await connection.getBalance(
+      accounts.treasuryVault,
+    );
 
     //check offer
     const acceptedOffer = await program.account.offer.fetch(nativeOffer);
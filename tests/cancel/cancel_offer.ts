@@ -13,7 +13,7 @@ import {
 import { createSplOffer, createNativeOffer } from "../helpers/create_offer";
 import { Accounts, generateAccounts, topUp } from "../helpers/helper";
 import { createMintAndAta, getBalance } from "../helpers/spl";
-import { CREATE_OFFER_AMOUNTS } from "../helpers/constants";
+import { CREATE_OFFER_AMOUNTS, SRC_EID } from "../helpers/constants";
 
 type Offer = {
   type: "monochain" | "crosschain";
@@ -49,6 +49,7 @@ describe("CancelOffer", () => {
     await program.methods
       .initialize({
         endpointProgram: new PublicKey(ENDPOINT_PROGRAM_ID),
+        eid: SRC_EID,
         treasury: treasury,
       })
       .accounts({
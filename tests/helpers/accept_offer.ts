@@ -1,5 +1,228 @@
 import { PublicKey } from "@solana/web3.js";
 import { BN, Program, IdlTypes } from "@coral-xyz/anchor";
 import { OtcMarket } from "../../target/types/otc_market";
+import {
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  getAssociatedTokenAddressSync,
+} from "@solana/spl-token";
+import { Accounts } from "./helper";
+import { EXCHANGE_RATE_SD } from "./constants";
+import {
+  getBuyerFill,
+  getEidFee,
+  getEscrow,
+  getEscrowLedger,
+  getFeeLedger,
+  getPause,
+  getReferrer,
+  getTokenFee,
+  getTreasury,
+} from "./pda";
+import { getTokenProgram } from "./spl";
 
 export type AcceptOfferParams = IdlTypes<OtcMarket>["AcceptOfferParams"];
+
+export const U64_MAX = new BN("18446744073709551615");
+
+export function getAcceptOfferParams(
+  offerId: number[],
+  srcAmountSd: BN,
+  srcBuyerAddress: number[],
+): AcceptOfferParams {
+  return {
+    offerId,
+    srcAmountSd,
+    srcBuyerAddress,
+    referrer: null,
+    referrerShareBps: 0,
+    maxExchangeRateSd: new BN(EXCHANGE_RATE_SD),
+    maxDstAmountLd: U64_MAX,
+    buyerMaxFillSd: new BN(0),
+    buyerProof: [],
+  };
+}
+
+function isNative(tokenAddress: number[]): boolean {
+  return (
+    tokenAddress.toString() ==
+    Array.from(PublicKey.default.toBytes()).toString()
+  );
+}
+
+export async function getQuoteAcceptOfferAccounts(
+  program: Program<OtcMarket>,
+  accounts: Pick<Accounts, "otcConfig">,
+  offer: PublicKey,
+) {
+  const offerAccount = await program.account.offer.fetch(offer);
+  const nativeDst = isNative(offerAccount.dstTokenAddress);
+
+  return {
+    otcConfig: accounts.otcConfig,
+    offer,
+    tokenFee: getTokenFee(
+      program.programId,
+      accounts.otcConfig,
+      offerAccount.dstTokenAddress,
+    ),
+    eidFee: getEidFee(
+      program.programId,
+      accounts.otcConfig,
+      offerAccount.srcEid,
+    ),
+    referrerConfig: null,
+    buyerFill: null,
+    dstTokenMint: nativeDst
+      ? null
+      : new PublicKey(offerAccount.dstTokenAddress),
+    tokenProgram: nativeDst
+      ? null
+      : await getTokenProgram(
+          program.provider.connection,
+          new PublicKey(offerAccount.dstTokenAddress),
+        ),
+    peer: null,
+    enforcedOptions: null,
+  };
+}
+
+// accounts of a monochain accept, the rent and the rounding dust are returned
+// to the offer payer when the accept fully fills the offer, the referrer ones
+// follow params.referrer
+export async function getAcceptOfferAccounts(
+  program: Program<OtcMarket>,
+  accounts: Pick<Accounts, "otcConfig" | "dstBuyer">,
+  offer: PublicKey,
+  params: AcceptOfferParams,
+) {
+  const offerAccount = await program.account.offer.fetch(offer);
+
+  const buyer = accounts.dstBuyer.publicKey;
+  const dstSeller = new PublicKey(offerAccount.dstSellerAddress);
+  const treasury = getTreasury(program.programId);
+  const escrow = getEscrow(program.programId, params.offerId);
+
+  const nativeSrc = isNative(offerAccount.srcTokenAddress);
+  const nativeDst = isNative(offerAccount.dstTokenAddress);
+  const srcTokenMint = nativeSrc
+    ? null
+    : new PublicKey(offerAccount.srcTokenAddress);
+  const dstTokenMint = nativeDst
+    ? null
+    : new PublicKey(offerAccount.dstTokenAddress);
+
+  // the src token program is stored in the offer, the dst one is the mint owner
+  const srcTokenProgram = nativeSrc
+    ? null
+    : new PublicKey(offerAccount.srcTokenProgram);
+  const dstTokenProgram = nativeDst
+    ? null
+    : await getTokenProgram(program.provider.connection, dstTokenMint);
+
+  const isFinalFill = offerAccount.srcAmountSd.eq(params.srcAmountSd);
+  const referrer = params.referrer;
+
+  return {
+    buyer,
+    otcConfig: accounts.otcConfig,
+    offer,
+    offerPayer: isFinalFill ? offerAccount.payer : null,
+    eidPause: getPause(
+      program.programId,
+      accounts.otcConfig,
+      offerAccount.srcEid,
+    ),
+    feeLedger: getFeeLedger(program.programId, offerAccount.dstTokenAddress),
+    tokenFee: getTokenFee(
+      program.programId,
+      accounts.otcConfig,
+      offerAccount.dstTokenAddress,
+    ),
+    eidFee: getEidFee(
+      program.programId,
+      accounts.otcConfig,
+      offerAccount.srcEid,
+    ),
+    buyerFill: offerAccount.buyersRoot
+      ? getBuyerFill(program.programId, params.offerId, buyer)
+      : null,
+    // dst
+    dstBuyerAta: nativeDst
+      ? null
+      : getAssociatedTokenAddressSync(
+          dstTokenMint,
+          buyer,
+          false,
+          dstTokenProgram,
+        ),
+    dstSellerAta: nativeDst
+      ? null
+      : getAssociatedTokenAddressSync(
+          dstTokenMint,
+          dstSeller,
+          false,
+          dstTokenProgram,
+        ),
+    dstSeller,
+    dstTreasuryAta: nativeDst
+      ? null
+      : getAssociatedTokenAddressSync(
+          dstTokenMint,
+          treasury,
+          true,
+          dstTokenProgram,
+        ),
+    treasury,
+    referrerConfig: referrer ? getReferrer(program.programId, referrer) : null,
+    referrer,
+    dstReferrerAta:
+      referrer && !nativeDst
+        ? getAssociatedTokenAddressSync(
+            dstTokenMint,
+            referrer,
+            false,
+            dstTokenProgram,
+          )
+        : null,
+    dstTokenMint,
+    // src
+    srcBuyerAta: nativeSrc
+      ? null
+      : getAssociatedTokenAddressSync(
+          srcTokenMint,
+          buyer,
+          false,
+          srcTokenProgram,
+        ),
+    srcSellerAta:
+      nativeSrc || !isFinalFill
+        ? null
+        : getAssociatedTokenAddressSync(
+            srcTokenMint,
+            offerAccount.payer,
+            false,
+            srcTokenProgram,
+          ),
+    srcEscrowAta: nativeSrc
+      ? null
+      : getAssociatedTokenAddressSync(
+          srcTokenMint,
+          escrow,
+          true,
+          srcTokenProgram,
+        ),
+    escrow,
+    escrowLedger: getEscrowLedger(
+      program.programId,
+      offerAccount.srcTokenAddress,
+    ),
+    srcTokenMint,
+    // crosschain
+    peer: null,
+    enforcedOptions: null,
+    associatedTokenProgram:
+      nativeSrc && nativeDst ? null : ASSOCIATED_TOKEN_PROGRAM_ID,
+    tokenProgram: dstTokenProgram,
+    srcTokenProgram,
+  };
+}
@@ -9,19 +9,50 @@ import {
   EXCHANGE_RATE_SD,
   SRC_EID,
 } from "./constants";
-import { mintTo } from "@solana/spl-token";
+import {
+  getAssociatedTokenAddressSync,
+  mintTo,
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  TOKEN_PROGRAM_ID,
+} from "@solana/spl-token";
+import {
+  getEscrow,
+  getEscrowLedger,
+  getFeeLedger,
+  getPause,
+  getSellerNonce,
+  getTreasury,
+} from "./pda";
 
 export type CreateOfferParams = IdlTypes<OtcMarket>["CreateOfferParams"];
 export type Offer = Omit<
   IdlAccounts<OtcMarket>["offer"],
-  "bump" | "srcAmountSd"
+  | "bump"
+  | "srcAmountSd"
+  | "expiresAt"
+  | "payer"
+  | "nonce"
+  | "minFillSd"
+  | "allOrNothing"
+  | "allowedBuyer"
+  | "buyersRoot"
+  | "srcTokenProgram"
+  | "srcGranularitySd"
+  | "cancelPending"
+  | "priceRevision"
 > & {
   srcAmountLd: BN;
+  expiresAt?: BN | null;
+  minFillSd?: BN;
+  allOrNothing?: boolean;
+  allowedBuyer?: number[] | null;
+  buyersRoot?: number[] | null;
 };
 
 export class OfferInfo {
   account: PublicKey;
   id: Uint8Array;
+  escrow?: PublicKey;
 }
 
 export async function getOfferAccount(
@@ -32,6 +63,7 @@ export async function getOfferAccount(
   srcTokenAddress: number[],
   dstTokenAddress: number[],
   exchangeRateSd: BN,
+  nonce: BN,
 ): Promise<OfferInfo> {
   const offerId: Uint8Array = await program.methods
     .hashOffer(
@@ -41,6 +73,7 @@ export async function getOfferAccount(
       srcTokenAddress,
       dstTokenAddress,
       exchangeRateSd,
+      nonce,
     )
     .view();
 
@@ -55,6 +88,17 @@ export async function getOfferAccount(
   };
 }
 
+export async function getNextNonce(
+  program: Program<OtcMarket>,
+  seller: PublicKey,
+): Promise<BN> {
+  const account = await program.account.sellerNonce.fetchNullable(
+    getSellerNonce(program.programId, seller),
+  );
+
+  return account ? account.nonce.addn(1) : new BN(1);
+}
+
 export async function createSplOffer(
   program: Program<OtcMarket>,
   connection: Connection,
@@ -83,8 +127,6 @@ export async function createOffer(
     | "srcToken"
     | "srcSellerAta"
     | "otcConfig"
-    | "escrow"
-    | "srcEscrowAta"
     | "dstSeller"
   >,
   offer: Offer,
@@ -113,6 +155,7 @@ export async function createOffer(
     );
   }
 
+  const nonce = await getNextNonce(program, accounts.srcSeller.publicKey);
   const offerInfo = await getOfferAccount(
     program,
     offer.srcSellerAddress,
@@ -121,29 +164,61 @@ export async function createOffer(
     offer.srcTokenAddress,
     offer.dstTokenAddress,
     offer.exchangeRateSd,
+    nonce,
   );
 
+  // escrow vault of the offer, created by the program
+  const escrow = getEscrow(program.programId, Array.from(offerInfo.id));
+  const treasury = getTreasury(program.programId);
+
   await program.methods
-    .createOffer({
-      dstSellerAddress: offer.dstSellerAddress,
-      dstEid: offer.dstEid,
-      dstTokenAddress: offer.dstTokenAddress,
-      srcAmountLd: offer.srcAmountLd,
-      exchangeRateSd: offer.exchangeRateSd,
-    })
+    .createOffer(
+      {
+        dstSellerAddress: offer.dstSellerAddress,
+        dstEid: offer.dstEid,
+        dstTokenAddress: offer.dstTokenAddress,
+        srcAmountLd: offer.srcAmountLd,
+        exchangeRateSd: offer.exchangeRateSd,
+        expiresAt: offer.expiresAt ?? null,
+        nonce,
+        minFillSd: offer.minFillSd ?? new BN(0),
+        allOrNothing: offer.allOrNothing ?? false,
+        allowedBuyer: offer.allowedBuyer ?? null,
+        buyersRoot: offer.buyersRoot ?? null,
+      },
+      // monochain offer - nothing to send
+      { nativeFee: new BN(0), lzTokenFee: new BN(0) },
+    )
     .accounts({
       seller: accounts.srcSeller.publicKey,
       offer: offerInfo.account,
+      sellerNonce: getSellerNonce(
+        program.programId,
+        accounts.srcSeller.publicKey,
+      ),
+      feeLedger: getFeeLedger(program.programId, offer.srcTokenAddress),
+      escrowLedger: getEscrowLedger(program.programId, offer.srcTokenAddress),
       otcConfig: accounts.otcConfig,
+      eidPause: getPause(program.programId, accounts.otcConfig, offer.dstEid),
+      escrow,
       srcTokenMint: isNative ? null : accounts.srcToken,
       srcSellerAta: isNative ? null : accounts.srcSellerAta,
-      escrowAta: isNative ? null : accounts.srcEscrowAta,
-      escrow: accounts.escrow,
+      srcEscrowAta: isNative
+        ? null
+        : getAssociatedTokenAddressSync(accounts.srcToken, escrow, true),
+      treasury,
+      srcTreasuryAta: isNative
+        ? null
+        : getAssociatedTokenAddressSync(accounts.srcToken, treasury, true),
+      peer: null,
+      enforcedOptions: null,
+      tokenProgram: isNative ? null : TOKEN_PROGRAM_ID,
+      associatedTokenProgram: isNative ? null : ASSOCIATED_TOKEN_PROGRAM_ID,
     })
     .signers([accounts.srcSeller])
     .rpc();
 
-  return offerInfo;
+  return { ...offerInfo, escrow };
 }
 
 export async function createNativeOffer(
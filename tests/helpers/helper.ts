@@ -18,11 +18,13 @@ import {
   TOP_UP_AMOUNT,
   TREASURY_SECRET_KEY,
 } from "./constants";
+import { getTreasury } from "./pda";
 
 export class Accounts {
   otcConfig: PublicKey;
   escrow: PublicKey;
   treasury: PublicKey;
+  treasuryVault: PublicKey;
   srcToken: PublicKey;
   dstToken: PublicKey;
   srcSeller: Keypair;
@@ -54,6 +56,8 @@ export async function generateAccounts(
   >
 > {
   const treasury = Keypair.fromSecretKey(TREASURY_SECRET_KEY).publicKey;
+  // fees accrue in the program-owned vault, withdraw_fees pays out to treasury
+  const treasuryVault = getTreasury(programId);
   const srcSeller = Keypair.generate();
   const dstSeller = Keypair.generate();
   const srcBuyer = Keypair.generate();
@@ -143,7 +147,8 @@ export async function generateAccounts(
       connection,
       payer,
       srcToken,
-      treasury,
+      treasuryVault,
+      true,
     )
   ).address;
   const dstTreasuryAta = (
@@ -151,7 +156,8 @@ export async function generateAccounts(
       connection,
       payer,
       dstToken,
-      treasury,
+      treasuryVault,
+      true,
     )
   ).address;
 
@@ -159,6 +165,7 @@ export async function generateAccounts(
     otcConfig,
     escrow,
     treasury,
+    treasuryVault,
     srcToken,
     dstToken,
     srcSeller,
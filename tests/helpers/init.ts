@@ -1,25 +1,26 @@
 import { Program } from "@coral-xyz/anchor";
 import { PublicKey, Keypair } from "@solana/web3.js";
 
-import { ENDPOINT_PROGRAM_ID } from "./constants";
+import { ENDPOINT_PROGRAM_ID, SRC_EID } from "./constants";
 import { OtcMarket } from "../../target/types/otc_market";
+import { getTreasury } from "./pda";
 
 export async function initOtc(
   program: Program<OtcMarket>,
   config: PublicKey,
-  escrow: PublicKey,
   treasury: PublicKey,
   payer: Keypair,
 ) {
   await program.methods
     .initialize({
       endpointProgram: new PublicKey(ENDPOINT_PROGRAM_ID),
+      eid: SRC_EID,
       treasury: treasury,
     })
     .accounts({
       payer: payer.publicKey,
       otcConfig: config,
-      escrow: escrow,
+      treasury: getTreasury(program.programId),
     })
     .signers([payer])
     .rpc();
@@ -0,0 +1,116 @@
+import * as anchor from "@coral-xyz/anchor";
+import { PublicKey } from "@solana/web3.js";
+
+export function getEscrow(programId: PublicKey, offerId: number[]): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("Escrow", "utf8"), Buffer.from(offerId)],
+    programId,
+  )[0];
+}
+
+export function getEscrowLedger(
+  programId: PublicKey,
+  tokenAddress: number[],
+): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("EscrowLedger", "utf8"), Buffer.from(tokenAddress)],
+    programId,
+  )[0];
+}
+
+export function getTreasury(programId: PublicKey): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("Treasury", "utf8")],
+    programId,
+  )[0];
+}
+
+export function getFeeLedger(
+  programId: PublicKey,
+  tokenAddress: number[],
+): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("FeeLedger", "utf8"), Buffer.from(tokenAddress)],
+    programId,
+  )[0];
+}
+
+export function getSellerNonce(
+  programId: PublicKey,
+  seller: PublicKey,
+): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("SellerNonce", "utf8"), seller.toBuffer()],
+    programId,
+  )[0];
+}
+
+export function getPause(
+  programId: PublicKey,
+  otcConfig: PublicKey,
+  eid: number,
+): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [
+      Buffer.from("Pause", "utf8"),
+      otcConfig.toBuffer(),
+      new anchor.BN(eid).toArrayLike(Buffer, "be", 4),
+    ],
+    programId,
+  )[0];
+}
+
+export function getTokenFee(
+  programId: PublicKey,
+  otcConfig: PublicKey,
+  dstTokenAddress: number[],
+): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [
+      Buffer.from("TokenFee", "utf8"),
+      otcConfig.toBuffer(),
+      Buffer.from(dstTokenAddress),
+    ],
+    programId,
+  )[0];
+}
+
+export function getEidFee(
+  programId: PublicKey,
+  otcConfig: PublicKey,
+  eid: number,
+): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [
+      Buffer.from("EidFee", "utf8"),
+      otcConfig.toBuffer(),
+      new anchor.BN(eid).toArrayLike(Buffer, "be", 4),
+    ],
+    programId,
+  )[0];
+}
+
+export function getReferrer(
+  programId: PublicKey,
+  referrer: PublicKey,
+): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("Referrer", "utf8"), referrer.toBuffer()],
+    programId,
+  )[0];
+}
+
+export function getBuyerFill(
+  programId: PublicKey,
+  offerId: number[],
+  buyer: PublicKey,
+): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [
+      Buffer.from("BuyerFill", "utf8"),
+      Buffer.from(offerId),
+      buyer.toBuffer(),
+    ],
+    programId,
+  )[0];
+}
@@ -36,11 +36,25 @@ export async function createMintAndAta(
 //   ).address;
 // }
 
+// spl token or token-2022 program owning the mint
+export async function getTokenProgram(
+  connection: Connection,
+  mint: PublicKey,
+): Promise<PublicKey> {
+  return (await connection.getAccountInfo(mint)).owner;
+}
+
 export async function getBalance(
   connection: Connection,
   tokenAccount: PublicKey,
+  tokenProgram?: PublicKey,
 ): Promise<Number> {
-  const info = await getAccount(connection, tokenAccount);
+  const info = await getAccount(
+    connection,
+    tokenAccount,
+    undefined,
+    tokenProgram,
+  );
 
   return Number(info.amount);
 }
@@ -6,7 +6,8 @@ import {
   PublicKey,
   Keypair,
 } from "@solana/web3.js";
-import { Program, Wallet } from "@coral-xyz/anchor";
+import { AnchorError, Program, Wallet } from "@coral-xyz/anchor";
+import { assert, expect } from "chai";
 import { OtcMarket } from "../../target/types/otc_market";
 
 import {
@@ -24,6 +25,7 @@ import { OtcPdaDeriver } from "./utils/otc-pda-deriver";
 import {
   COMMITMENT,
   ENDPOINT_PROGRAM_ID,
+  SOLANA_EID,
   TREASURY_SECRET_KEY,
 } from "./config/constants";
 import { Options, Packet } from "@layerzerolabs/lz-v2-utilities";
@@ -43,7 +45,6 @@ describe("Omnichain", () => {
     otcConfig: PublicKey;
     endpoint: PublicKey;
     treasury: PublicKey;
-    escrow: PublicKey;
     lzReceiveTypesAccounts: PublicKey;
   };
   let endpoint: EndpointProgram.Endpoint;
@@ -54,7 +55,6 @@ describe("Omnichain", () => {
       otcConfig: otcPdaDeriver.config(),
       endpoint: new PublicKey(ENDPOINT_PROGRAM_ID),
       treasury: Keypair.fromSecretKey(TREASURY_SECRET_KEY).publicKey,
-      escrow: otcPdaDeriver.escrow(),
       lzReceiveTypesAccounts: otcPdaDeriver.lzReceiveTypesAccounts(),
     };
 
@@ -72,29 +72,46 @@ describe("Omnichain", () => {
     // );
   });
 
+  const initialize = (treasury: PublicKey) =>
+    program.methods
+      .initialize({
+        endpointProgram: accounts.endpoint,
+        eid: SOLANA_EID,
+        treasury,
+      })
+      .accounts({
+        payer: wallet.publicKey,
+        lzReceiveTypesAccounts: accounts.lzReceiveTypesAccounts,
+        otcConfig: accounts.otcConfig,
+        treasury: otcPdaDeriver.treasury(),
+      })
+      .remainingAccounts(
+        endpoint.getRegisterOappIxAccountMetaForCPI(
+          wallet.publicKey,
+          accounts.otcConfig,
+        ),
+      )
+      .signers([wallet.payer])
+      .rpc({
+        commitment,
+      });
+
   describe("Initialize", () => {
+    it("should revert on InvalidTreasury", async () => {
+      try {
+        await initialize(PublicKey.default);
+
+        assert(false, "should revert");
+      } catch (error: any) {
+        expect(error).to.be.instanceOf(AnchorError);
+        expect((error as AnchorError).error.errorCode.code).to.equal(
+          "InvalidTreasury",
+        );
+      }
+    });
+
     it("should init otc", async () => {
-      await program.methods
-        .initialize({
-          endpointProgram: accounts.endpoint,
-          treasury: accounts.treasury,
-        })
-        .accounts({
-          payer: wallet.publicKey,
-          lzReceiveTypesAccounts: accounts.lzReceiveTypesAccounts,
-          otcConfig: accounts.otcConfig,
-          escrow: accounts.escrow,
-        })
-        .remainingAccounts(
-          endpoint.getRegisterOappIxAccountMetaForCPI(
-            wallet.publicKey,
-            accounts.otcConfig,
-          ),
-        )
-        .signers([wallet.payer])
-        .rpc({
-          commitment,
-        });
+      await initialize(accounts.treasury);
     });
 
     it("should configure crosschain", async () => {
@@ -0,0 +1,136 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Connection, Keypair, PublicKey } from "@solana/web3.js";
+import { AnchorError, Program, Wallet } from "@coral-xyz/anchor";
+import { OtcMarket } from "../../target/types/otc_market";
+import { assert, expect } from "chai";
+import { Otc } from "./utils/otc";
+import { OtcTools } from "./utils/otc-tools";
+import { AccountTools } from "./utils/account-tools";
+import { AmountsLD, COMMITMENT, SOLANA_EID } from "./config/constants";
+
+async function getClusterTime(connection: Connection): Promise<number> {
+  return await connection.getBlockTime(await connection.getSlot(COMMITMENT));
+}
+
+describe("Reclaim Expired Offer", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+  const program = anchor.workspace.OtcMarket as Program<OtcMarket>;
+  const connection = provider.connection;
+  const wallet = provider.wallet as Wallet;
+
+  const otc = new Otc(program, connection, wallet.payer);
+  const nativeTokenAddress = Array.from(PublicKey.default.toBytes());
+
+  let accounts: {
+    otcConfig: PublicKey;
+    seller: Keypair;
+  };
+
+  const reclaimExpiredOffer = (offer: [PublicKey, number[]]) =>
+    program.methods
+      .reclaimExpiredOffer(
+        offer[1],
+        { nativeFee: new anchor.BN(0), lzTokenFee: new anchor.BN(0) },
+        Buffer.from([]),
+      )
+      .accounts({
+        // anyone can reclaim, the seller is only the recipient
+        payer: wallet.publicKey,
+        seller: accounts.seller.publicKey,
+        otcConfig: accounts.otcConfig,
+        offer: offer[0],
+        eidPause: otc.deriver.pause(SOLANA_EID),
+        srcSellerAta: null,
+        srcEscrowAta: null,
+        escrow: otc.deriver.escrow(offer[1]),
+        escrowLedger: otc.deriver.escrowLedger(nativeTokenAddress),
+        srcTokenMint: null,
+        peer: null,
+        enforcedOptions: null,
+        tokenProgram: null,
+      })
+      .signers([wallet.payer])
+      .rpc({ commitment: COMMITMENT });
+
+  before(async () => {
+    accounts = {
+      otcConfig: otc.deriver.config(),
+      seller: Keypair.generate(),
+    };
+
+    await AccountTools.topUpAccounts(otc, accounts.seller);
+  });
+
+  after(async () => {
+    await AccountTools.getRemainings(
+      connection,
+      [accounts.seller],
+      wallet.publicKey,
+    );
+  });
+
+  it("should revert on OfferNotExpired", async () => {
+    const expiresAt = (await getClusterTime(connection)) + 60 * 60;
+    const offer = await OtcTools.createOffer(
+      otc,
+      accounts.seller,
+      undefined,
+      null,
+      undefined,
+      { expiresAt: new anchor.BN(expiresAt) },
+    );
+
+    try {
+      await reclaimExpiredOffer(offer);
+
+      assert(false, "should revert");
+    } catch (error: any) {
+      expect(error).to.be.instanceOf(AnchorError);
+      expect((error as AnchorError).error.errorCode.code).to.equal(
+        "OfferNotExpired",
+      );
+    }
+  });
+
+  it("should reclaim expired offer", async () => {
+    const expiresAt = (await getClusterTime(connection)) + 10;
+    const offer = await OtcTools.createOffer(
+      otc,
+      accounts.seller,
+      undefined,
+      null,
+      undefined,
+      { expiresAt: new anchor.BN(expiresAt) },
+    );
+    const escrow = otc.deriver.escrow(offer[1]);
+
+    while ((await getClusterTime(connection)) <= expiresAt) {
+      await new Promise((resolve) => setTimeout(resolve, 2_000));
+    }
+
+    const initialSellerBalance = await connection.getBalance(
+      accounts.seller.publicKey,
+    );
+    const offerRent = await connection.getBalance(offer[0]);
+    const escrowBalance = await connection.getBalance(escrow);
+
+    await reclaimExpiredOffer(offer);
+
+    const sellerBalance = await connection.getBalance(
+      accounts.seller.publicKey,
+    );
+
+    assert(
+      (await connection.getAccountInfo(offer[0])) === null,
+      "offer closed",
+    );
+    assert((await connection.getAccountInfo(escrow)) === null, "escrow closed");
+    // the escrowed amount and the rents, the payer covered the tx fee
+    assert(
+      sellerBalance - initialSellerBalance == offerRent + escrowBalance,
+      "seller balance",
+    );
+    assert(escrowBalance > AmountsLD.SOL, "escrowed amount");
+  });
+});
@@ -0,0 +1,113 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { AnchorError, Program, Wallet } from "@coral-xyz/anchor";
+import { OtcMarket } from "../../target/types/otc_market";
+import { assert, expect } from "chai";
+import { Otc } from "./utils/otc";
+import { OtcTools } from "./utils/otc-tools";
+import { AccountTools } from "./utils/account-tools";
+import { COMMITMENT } from "./config/constants";
+
+// NOTICE: configs and offers of the legacy layouts can not be created anymore,
+// the migration itself is covered by the legacy_config_migrates unit test and
+// only the rejection of accounts that do not need a migration is covered here
+describe("Migrate Offer", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+  const program = anchor.workspace.OtcMarket as Program<OtcMarket>;
+  const connection = provider.connection;
+  const wallet = provider.wallet as Wallet;
+
+  const otc = new Otc(program, connection, wallet.payer);
+
+  let accounts: {
+    otcConfig: PublicKey;
+    seller: Keypair;
+    offer: [PublicKey, number[]];
+  };
+
+  const migrateConfig = (admin: Keypair) =>
+    program.methods
+      .migrateConfig()
+      .accounts({
+        admin: admin.publicKey,
+        otcConfig: accounts.otcConfig,
+        treasury: otc.deriver.treasury(),
+      })
+      .signers([admin])
+      .rpc({ commitment: COMMITMENT });
+
+  const migrateOffer = (payer: Keypair) =>
+    program.methods
+      .migrateOffer(accounts.offer[1])
+      .accounts({
+        payer: payer.publicKey,
+        otcConfig: accounts.otcConfig,
+        offer: accounts.offer[0],
+        legacyEscrow: null,
+        legacyEscrowAta: null,
+        escrow: null,
+        srcEscrowAta: null,
+        escrowLedger: null,
+        srcTokenMint: null,
+        associatedTokenProgram: null,
+        tokenProgram: null,
+      })
+      .signers([payer])
+      .rpc({ commitment: COMMITMENT });
+
+  before(async () => {
+    const seller = Keypair.generate();
+    await AccountTools.topUpAccounts(otc, seller);
+
+    accounts = {
+      otcConfig: otc.deriver.config(),
+      seller,
+      offer: await OtcTools.createOffer(otc, seller),
+    };
+  });
+
+  after(async () => {
+    await AccountTools.getRemainings(
+      connection,
+      [accounts.seller],
+      wallet.publicKey,
+    );
+  });
+
+  it("should revert on InvalidLegacyConfig", async () => {
+    try {
+      await migrateConfig(wallet.payer);
+
+      assert(false, "should revert");
+    } catch (error: any) {
+      expect(error).to.be.instanceOf(AnchorError);
+      expect((error as AnchorError).error.errorCode.code).to.equal(
+        "InvalidLegacyConfig",
+      );
+    }
+
+    // the current config is left untouched
+    const otcConfig = await program.account.otcConfig.fetch(
+      accounts.otcConfig,
+    );
+    expect(otcConfig.admin.toBase58()).to.equal(wallet.publicKey.toBase58());
+  });
+
+  it("should revert on InvalidLegacyOffer", async () => {
+    try {
+      await migrateOffer(accounts.seller);
+
+      assert(false, "should revert");
+    } catch (error: any) {
+      expect(error).to.be.instanceOf(AnchorError);
+      expect((error as AnchorError).error.errorCode.code).to.equal(
+        "InvalidLegacyOffer",
+      );
+    }
+
+    // the current offer is left untouched
+    const offer = await program.account.offer.fetch(accounts.offer[0]);
+    assert(offer.nonce.gtn(0), "offer keeps its nonce");
+  });
+});
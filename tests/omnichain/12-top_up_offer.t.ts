@@ -0,0 +1,158 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { AnchorError, Program, Wallet } from "@coral-xyz/anchor";
+import { OtcMarket } from "../../target/types/otc_market";
+import { assert, expect } from "chai";
+import { Otc } from "./utils/otc";
+import { OtcTools } from "./utils/otc-tools";
+import { AccountTools } from "./utils/account-tools";
+import {
+  AmountsLD,
+  COMMITMENT,
+  Decimals,
+  GAS,
+  SOLANA_EID,
+} from "./config/constants";
+
+describe("Top Up Offer", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+  const program = anchor.workspace.OtcMarket as Program<OtcMarket>;
+  const connection = provider.connection;
+  const wallet = provider.wallet as Wallet;
+
+  const otc = new Otc(program, connection, wallet.payer);
+  const nativeTokenAddress = Array.from(PublicKey.default.toBytes());
+  const decimalConversionRate = 10 ** (Decimals.SOL - Decimals.SD);
+
+  let accounts: {
+    otcConfig: PublicKey;
+    seller: Keypair;
+    offer: [PublicKey, number[]];
+  };
+
+  const topUpOffer = (seller: Keypair, srcAmountLd: number) =>
+    program.methods
+      .topUpOffer(
+        { offerId: accounts.offer[1], srcAmountLd: new anchor.BN(srcAmountLd) },
+        { nativeFee: new anchor.BN(0), lzTokenFee: new anchor.BN(0) },
+      )
+      .accounts({
+        seller: seller.publicKey,
+        offer: accounts.offer[0],
+        feeLedger: otc.deriver.feeLedger(nativeTokenAddress),
+        escrowLedger: otc.deriver.escrowLedger(nativeTokenAddress),
+        otcConfig: accounts.otcConfig,
+        eidPause: otc.deriver.pause(SOLANA_EID),
+        escrow: otc.deriver.escrow(accounts.offer[1]),
+        srcTokenMint: null,
+        srcSellerAta: null,
+        srcEscrowAta: null,
+        treasury: otc.deriver.treasury(),
+        srcTreasuryAta: null,
+        peer: null,
+        enforcedOptions: null,
+        tokenProgram: null,
+        associatedTokenProgram: null,
+      })
+      .signers([seller])
+      .rpc({ commitment: COMMITMENT });
+
+  before(async () => {
+    const seller = Keypair.generate();
+    await AccountTools.topUpAccounts(otc, seller);
+    await AccountTools.transferSol(
+      connection,
+      wallet.payer,
+      seller.publicKey,
+      GAS,
+    );
+
+    accounts = {
+      otcConfig: otc.deriver.config(),
+      seller,
+      offer: await OtcTools.createOffer(otc, seller),
+    };
+  });
+
+  after(async () => {
+    await AccountTools.getRemainings(
+      connection,
+      [accounts.seller],
+      wallet.publicKey,
+    );
+  });
+
+  describe("Quote Top Up Offer", () => {
+    it("should quote top up", async () => {
+      const config = await program.account.otcConfig.fetch(accounts.otcConfig);
+
+      const [receipt, fee] = await otc.quoteTopUpOffer(
+        {
+          offerId: accounts.offer[1],
+          srcAmountLd: new anchor.BN(AmountsLD.SOL),
+        },
+        accounts.seller,
+      );
+
+      assert(receipt.srcAmountLd.eqn(AmountsLD.SOL), "src amount");
+      assert(receipt.makerFeeBps == config.makerFeeBps, "maker fee bps");
+      assert(
+        receipt.escrowedAmountLd.add(receipt.makerFeeLd).eqn(AmountsLD.SOL),
+        "escrowed amount",
+      );
+      assert(fee.nativeFee.eqn(0), "monochain offer - no messaging fee");
+    });
+  });
+
+  describe("Top Up Offer", () => {
+    it("should revert on OnlySeller", async () => {
+      const stranger = Keypair.generate();
+
+      try {
+        await topUpOffer(stranger, AmountsLD.SOL);
+
+        assert(false, "should revert");
+      } catch (error: any) {
+        expect(error).to.be.instanceOf(AnchorError);
+        expect((error as AnchorError).error.errorCode.code).to.equal(
+          "OnlySeller",
+        );
+      }
+    });
+
+    it("should revert on InvalidAmount", async () => {
+      try {
+        // less than one shared decimals unit - all dust
+        await topUpOffer(accounts.seller, decimalConversionRate - 1);
+
+        assert(false, "should revert");
+      } catch (error: any) {
+        expect(error).to.be.instanceOf(AnchorError);
+        expect((error as AnchorError).error.errorCode.code).to.equal(
+          "InvalidAmount",
+        );
+      }
+    });
+
+    it("should top up offer", async () => {
+      const escrow = otc.deriver.escrow(accounts.offer[1]);
+      const initialOffer = await program.account.offer.fetch(accounts.offer[0]);
+      const initialEscrowBalance = await connection.getBalance(escrow);
+
+      await topUpOffer(accounts.seller, AmountsLD.SOL);
+
+      const offer = await program.account.offer.fetch(accounts.offer[0]);
+      const escrowBalance = await connection.getBalance(escrow);
+      const escrowedAmountLd = escrowBalance - initialEscrowBalance;
+
+      assert(escrowedAmountLd > 0, "escrowed amount");
+      assert(
+        offer.srcAmountSd
+          .sub(initialOffer.srcAmountSd)
+          .eqn(escrowedAmountLd / decimalConversionRate),
+        "src amount",
+      );
+    });
+  });
+});
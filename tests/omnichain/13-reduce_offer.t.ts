@@ -0,0 +1,173 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { AnchorError, Program, Wallet } from "@coral-xyz/anchor";
+import { OtcMarket } from "../../target/types/otc_market";
+import { assert, expect } from "chai";
+import { Otc } from "./utils/otc";
+import { OtcTools } from "./utils/otc-tools";
+import { AccountTools } from "./utils/account-tools";
+import { COMMITMENT, Decimals, SOLANA_EID } from "./config/constants";
+import { addressToBytes32, Options } from "@layerzerolabs/lz-v2-utilities";
+import { solanaToArbSepConfig as peer } from "./config/peer";
+
+describe("Reduce Offer", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+  const program = anchor.workspace.OtcMarket as Program<OtcMarket>;
+  const connection = provider.connection;
+  const wallet = provider.wallet as Wallet;
+
+  const otc = new Otc(program, connection, wallet.payer);
+  const nativeTokenAddress = Array.from(PublicKey.default.toBytes());
+  const decimalConversionRate = 10 ** (Decimals.SOL - Decimals.SD);
+
+  let accounts: {
+    otcConfig: PublicKey;
+    seller: Keypair;
+    offer: [PublicKey, number[]];
+  };
+
+  const reduceOffer = (srcAmountSd: anchor.BN) =>
+    program.methods
+      .reduceOffer(
+        { offerId: accounts.offer[1], srcAmountSd },
+        { nativeFee: new anchor.BN(0), lzTokenFee: new anchor.BN(0) },
+        Buffer.from([]),
+      )
+      .accounts({
+        seller: accounts.seller.publicKey,
+        otcConfig: accounts.otcConfig,
+        offer: accounts.offer[0],
+        eidPause: otc.deriver.pause(SOLANA_EID),
+        srcSellerAta: null,
+        srcEscrowAta: null,
+        escrow: otc.deriver.escrow(accounts.offer[1]),
+        escrowLedger: otc.deriver.escrowLedger(nativeTokenAddress),
+        srcTokenMint: null,
+        peer: null,
+        enforcedOptions: null,
+        tokenProgram: null,
+      })
+      .signers([accounts.seller])
+      .rpc({ commitment: COMMITMENT });
+
+  before(async () => {
+    const seller = Keypair.generate();
+    await AccountTools.topUpAccounts(otc, seller);
+
+    accounts = {
+      otcConfig: otc.deriver.config(),
+      seller,
+      offer: await OtcTools.createOffer(otc, seller),
+    };
+  });
+
+  after(async () => {
+    await AccountTools.getRemainings(
+      connection,
+      [accounts.seller],
+      wallet.publicKey,
+    );
+  });
+
+  describe("Quote Reduce Offer", () => {
+    let crosschainOffer: [PublicKey, number[]];
+
+    before(async () => {
+      const dstSellerAddress = Array.from(
+        addressToBytes32("0xC37713ef41Aff1A7ac1c3D02f6f0B3a57F8A3091"),
+      );
+      crosschainOffer = await OtcTools.createOffer(
+        otc,
+        accounts.seller,
+        dstSellerAddress,
+      );
+    });
+
+    it("should revert on ExcessiveAmount", async () => {
+      const offer = await program.account.offer.fetch(crosschainOffer[0]);
+
+      try {
+        await program.methods
+          .quoteReduceOffer(
+            Array.from(accounts.seller.publicKey.toBytes()),
+            { offerId: crosschainOffer[1], srcAmountSd: offer.srcAmountSd },
+            Buffer.from([]),
+            false,
+          )
+          .accounts({
+            otcConfig: accounts.otcConfig,
+            offer: crosschainOffer[0],
+            peer: otc.deriver.peer(peer.to.eid),
+            enforcedOptions: otc.deriver.enforcedOptions(peer.to.eid),
+          })
+          .view();
+
+        assert(false, "should revert");
+      } catch (error: any) {
+        expect(error.simulationResponse.logs.join("\n")).to.contain(
+          "Error Code: ExcessiveAmount",
+        );
+      }
+    });
+
+    it("should quote reduce of crosschain offer", async () => {
+      const fee = await otc.quoteReduceOffer(
+        { offerId: crosschainOffer[1], srcAmountSd: new anchor.BN(1) },
+        Buffer.from(
+          Options.newOptions()
+            .addExecutorLzReceiveOption(1 * 10 ** 6, 0)
+            .toBytes(),
+        ),
+      );
+
+      assert(fee.nativeFee.gtn(0), "native fee");
+      assert(fee.lzTokenFee.eqn(0), "lz token fee");
+    });
+  });
+
+  describe("Reduce Offer", () => {
+    it("should revert on ExcessiveAmount", async () => {
+      const offer = await program.account.offer.fetch(accounts.offer[0]);
+
+      try {
+        // the whole offer is withdrawn with cancel_offer
+        await reduceOffer(offer.srcAmountSd);
+
+        assert(false, "should revert");
+      } catch (error: any) {
+        expect(error).to.be.instanceOf(AnchorError);
+        expect((error as AnchorError).error.errorCode.code).to.equal(
+          "ExcessiveAmount",
+        );
+      }
+    });
+
+    it("should reduce offer", async () => {
+      const srcAmountSd = new anchor.BN(23);
+      const initialOffer = await program.account.offer.fetch(accounts.offer[0]);
+      const initialSellerBalance = await connection.getBalance(
+        accounts.seller.publicKey,
+      );
+
+      await reduceOffer(srcAmountSd);
+
+      const offer = await program.account.offer.fetch(accounts.offer[0]);
+      const sellerBalance = await connection.getBalance(
+        accounts.seller.publicKey,
+      );
+
+      assert(
+        initialOffer.srcAmountSd.sub(offer.srcAmountSd).eq(srcAmountSd),
+        "src amount",
+      );
+      // the seller paid the tx fee out of the returned amount
+      assert(
+        sellerBalance > initialSellerBalance &&
+          sellerBalance - initialSellerBalance <=
+            srcAmountSd.toNumber() * decimalConversionRate,
+        "seller balance",
+      );
+    });
+  });
+});
@@ -0,0 +1,136 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { AnchorError, Program, Wallet } from "@coral-xyz/anchor";
+import { OtcMarket } from "../../target/types/otc_market";
+import { assert, expect } from "chai";
+import { Otc } from "./utils/otc";
+import { OtcTools } from "./utils/otc-tools";
+import { AccountTools } from "./utils/account-tools";
+import { COMMITMENT, GAS } from "./config/constants";
+import { arrayify, keccak256 } from "ethers/lib/utils";
+
+describe("Close Buyer Fill", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+  const program = anchor.workspace.OtcMarket as Program<OtcMarket>;
+  const connection = provider.connection;
+  const wallet = provider.wallet as Wallet;
+
+  const otc = new Otc(program, connection, wallet.payer);
+
+  const buyerMaxFillSd = new anchor.BN(123);
+
+  let accounts: {
+    otcConfig: PublicKey;
+    seller: Keypair;
+    buyer: Keypair;
+    offer: [PublicKey, number[]];
+    buyerFill: PublicKey;
+  };
+
+  const closeBuyerFill = () =>
+    program.methods
+      .closeBuyerFill(accounts.offer[1])
+      .accounts({
+        buyer: accounts.buyer.publicKey,
+        offer: accounts.offer[0],
+        buyerFill: accounts.buyerFill,
+      })
+      .signers([accounts.buyer])
+      .rpc({ commitment: COMMITMENT });
+
+  before(async () => {
+    const seller = Keypair.generate();
+    const buyer = Keypair.generate();
+
+    await AccountTools.topUpAccounts(otc, seller);
+    await AccountTools.transferSol(
+      connection,
+      wallet.payer,
+      buyer.publicKey,
+      GAS,
+    );
+
+    // single buyer allowlist - the leaf is the root, the proof is empty
+    const leaf = keccak256(
+      Buffer.concat([
+        buyer.publicKey.toBuffer(),
+        buyerMaxFillSd.toArrayLike(Buffer, "be", 8),
+      ]),
+    );
+    const offer = await OtcTools.createOffer(
+      otc,
+      seller,
+      undefined,
+      null,
+      undefined,
+      { buyersRoot: Array.from(arrayify(leaf)) },
+    );
+
+    accounts = {
+      otcConfig: otc.deriver.config(),
+      seller,
+      buyer,
+      offer,
+      buyerFill: otc.deriver.buyerFill(offer[1], buyer.publicKey),
+    };
+  });
+
+  after(async () => {
+    await AccountTools.getRemainings(
+      connection,
+      [accounts.seller, accounts.buyer],
+      wallet.publicKey,
+    );
+  });
+
+  it("should revert on OfferNotClosed", async () => {
+    await OtcTools.acceptOffer(otc, accounts.offer, accounts.buyer, {
+      srcAmountSd: new anchor.BN(1),
+      buyerMaxFillSd,
+    });
+
+    try {
+      await closeBuyerFill();
+
+      assert(false, "should revert");
+    } catch (error: any) {
+      expect(error).to.be.instanceOf(AnchorError);
+      expect((error as AnchorError).error.errorCode.code).to.equal(
+        "OfferNotClosed",
+      );
+    }
+  });
+
+  it("should close buyer fill of closed offer", async () => {
+    const offer = await program.account.offer.fetch(accounts.offer[0]);
+    await OtcTools.acceptOffer(otc, accounts.offer, accounts.buyer, {
+      srcAmountSd: offer.srcAmountSd,
+      buyerMaxFillSd,
+    });
+
+    const buyerFill = await program.account.buyerFill.fetch(
+      accounts.buyerFill,
+    );
+    assert(buyerFill.filledSd.eq(buyerMaxFillSd), "filled sd");
+    assert(
+      (await connection.getAccountInfo(accounts.offer[0])) === null,
+      "offer closed",
+    );
+
+    const buyerFillRent = await connection.getBalance(accounts.buyerFill);
+    const initialBuyerBalance = await connection.getBalance(
+      accounts.buyer.publicKey,
+    );
+
+    await closeBuyerFill();
+
+    const buyerBalance = await connection.getBalance(accounts.buyer.publicKey);
+
+    assert(
+      (await connection.getAccountInfo(accounts.buyerFill)) === null,
+      "buyer fill closed",
+    );
+    assert(buyerBalance - initialBuyerBalance == buyerFillRent, "buyer balance");
+  });
+});
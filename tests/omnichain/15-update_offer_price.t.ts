@@ -0,0 +1,189 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { AnchorError, Program, Wallet } from "@coral-xyz/anchor";
+import { OtcMarket } from "../../target/types/otc_market";
+import { assert, expect } from "chai";
+import { Otc } from "./utils/otc";
+import { OtcTools } from "./utils/otc-tools";
+import { AccountTools } from "./utils/account-tools";
+import { COMMITMENT, ExchangeRates, SOLANA_EID } from "./config/constants";
+import {
+  getAcceptOfferParams,
+  getQuoteAcceptOfferAccounts,
+} from "../helpers/accept_offer";
+
+describe("Update Offer Price", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+  const program = anchor.workspace.OtcMarket as Program<OtcMarket>;
+  const connection = provider.connection;
+  const wallet = provider.wallet as Wallet;
+
+  const otc = new Otc(program, connection, wallet.payer);
+
+  const newExchangeRateSd = new anchor.BN(2 * ExchangeRates.OneToOne);
+
+  let accounts: {
+    otcConfig: PublicKey;
+    seller: Keypair;
+    buyer: Keypair;
+    offer: [PublicKey, number[]];
+  };
+
+  const updateOfferPrice = (seller: Keypair, exchangeRateSd: anchor.BN) =>
+    program.methods
+      .updateOfferPrice(
+        { offerId: accounts.offer[1], exchangeRateSd },
+        { nativeFee: new anchor.BN(0), lzTokenFee: new anchor.BN(0) },
+      )
+      .accounts({
+        seller: seller.publicKey,
+        otcConfig: accounts.otcConfig,
+        offer: accounts.offer[0],
+        eidPause: otc.deriver.pause(SOLANA_EID),
+        peer: null,
+        enforcedOptions: null,
+      })
+      .signers([seller])
+      .rpc({ commitment: COMMITMENT });
+
+  before(async () => {
+    const seller = Keypair.generate();
+    await AccountTools.topUpAccounts(otc, seller);
+
+    accounts = {
+      otcConfig: otc.deriver.config(),
+      seller,
+      buyer: Keypair.generate(),
+      offer: await OtcTools.createOffer(otc, seller),
+    };
+  });
+
+  after(async () => {
+    await AccountTools.getRemainings(
+      connection,
+      [accounts.seller],
+      wallet.publicKey,
+    );
+  });
+
+  describe("Quote Update Offer Price", () => {
+    it("should revert on InvalidPricing", async () => {
+      try {
+        await program.methods
+          .quoteUpdateOfferPrice(
+            { offerId: accounts.offer[1], exchangeRateSd: new anchor.BN(0) },
+            false,
+          )
+          .accounts({
+            otcConfig: accounts.otcConfig,
+            offer: accounts.offer[0],
+            peer: null,
+            enforcedOptions: null,
+          })
+          .view();
+
+        assert(false, "should revert");
+      } catch (error: any) {
+        expect(error.simulationResponse.logs.join("\n")).to.contain(
+          "Error Code: InvalidPricing",
+        );
+      }
+    });
+
+    it("should quote zero fee for monochain offer", async () => {
+      const fee = await program.methods
+        .quoteUpdateOfferPrice(
+          { offerId: accounts.offer[1], exchangeRateSd: newExchangeRateSd },
+          false,
+        )
+        .accounts({
+          otcConfig: accounts.otcConfig,
+          offer: accounts.offer[0],
+          peer: null,
+          enforcedOptions: null,
+        })
+        .view();
+
+      assert(fee.nativeFee.eqn(0), "native fee");
+      assert(fee.lzTokenFee.eqn(0), "lz token fee");
+    });
+  });
+
+  describe("Update Offer Price", () => {
+    it("should revert on OnlySeller", async () => {
+      try {
+        await updateOfferPrice(wallet.payer, newExchangeRateSd);
+
+        assert(false, "should revert");
+      } catch (error: any) {
+        expect(error).to.be.instanceOf(AnchorError);
+        expect((error as AnchorError).error.errorCode.code).to.equal(
+          "OnlySeller",
+        );
+      }
+    });
+
+    it("should update offer price", async () => {
+      const initialOffer = await program.account.offer.fetch(accounts.offer[0]);
+
+      await updateOfferPrice(accounts.seller, newExchangeRateSd);
+
+      const offer = await program.account.offer.fetch(accounts.offer[0]);
+
+      assert(offer.exchangeRateSd.eq(newExchangeRateSd), "exchange rate");
+      assert(
+        offer.priceRevision.eq(initialOffer.priceRevision.addn(1)),
+        "price revision",
+      );
+    });
+
+    it("should revert stale accept on ExcessiveExchangeRate", async () => {
+      try {
+        await OtcTools.acceptOffer(otc, accounts.offer, accounts.buyer, {
+          srcAmountSd: new anchor.BN(1),
+          maxExchangeRateSd: new anchor.BN(ExchangeRates.OneToOne),
+        });
+
+        assert(false, "should revert");
+      } catch (error: any) {
+        expect(error).to.be.instanceOf(AnchorError);
+        expect((error as AnchorError).error.errorCode.code).to.equal(
+          "ExcessiveExchangeRate",
+        );
+      }
+    });
+
+    it("should revert stale quote on ExcessiveExchangeRate", async () => {
+      try {
+        await program.methods
+          .quoteAcceptOffer(
+            Array.from(accounts.buyer.publicKey.toBytes()),
+            {
+              ...getAcceptOfferParams(
+                accounts.offer[1],
+                new anchor.BN(1),
+                Array.from(accounts.buyer.publicKey.toBytes()),
+              ),
+              maxExchangeRateSd: new anchor.BN(ExchangeRates.OneToOne),
+            },
+            false,
+          )
+          .accounts(
+            await getQuoteAcceptOfferAccounts(
+              program,
+              { otcConfig: accounts.otcConfig },
+              accounts.offer[0],
+            ),
+          )
+          .view();
+
+        assert(false, "should revert");
+      } catch (error: any) {
+        expect(error.simulationResponse.logs.join("\n")).to.contain(
+          "Error Code: ExcessiveExchangeRate",
+        );
+      }
+    });
+  });
+});
@@ -0,0 +1,89 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { AnchorError, Program, Wallet } from "@coral-xyz/anchor";
+import { OtcMarket } from "../../target/types/otc_market";
+import { assert, expect } from "chai";
+import { Otc } from "./utils/otc";
+import { COMMITMENT } from "./config/constants";
+
+describe("Token Extension Policy", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+  const program = anchor.workspace.OtcMarket as Program<OtcMarket>;
+  const connection = provider.connection;
+  const wallet = provider.wallet as Wallet;
+
+  const otc = new Otc(program, connection, wallet.payer);
+
+  const ALLOW_CONFIDENTIAL_TRANSFER = 1;
+
+  let otcConfig: PublicKey;
+
+  before(async () => {
+    otcConfig = otc.deriver.config();
+  });
+
+  it("should revert on Unauthorized", async () => {
+    const stranger = Keypair.generate();
+
+    try {
+      await program.methods
+        .setTokenExtensionPolicy({ tokenExtensionPolicy: 0 })
+        .accounts({ admin: stranger.publicKey, otcConfig })
+        .signers([stranger])
+        .rpc({ commitment: COMMITMENT });
+
+      assert(false, "should revert");
+    } catch (error: any) {
+      expect(error).to.be.instanceOf(AnchorError);
+      expect((error as AnchorError).error.errorCode.code).to.equal(
+        "Unauthorized",
+      );
+    }
+  });
+
+  it("should revert on UnsupportedTokenExtension", async () => {
+    try {
+      await program.methods
+        .setTokenExtensionPolicy({
+          tokenExtensionPolicy: ALLOW_CONFIDENTIAL_TRANSFER << 1,
+        })
+        .accounts({ admin: wallet.publicKey, otcConfig })
+        .signers([wallet.payer])
+        .rpc({ commitment: COMMITMENT });
+
+      assert(false, "should revert");
+    } catch (error: any) {
+      expect(error).to.be.instanceOf(AnchorError);
+      expect((error as AnchorError).error.errorCode.code).to.equal(
+        "UnsupportedTokenExtension",
+      );
+    }
+  });
+
+  it("should set token extension policy", async () => {
+    const initialConfig = await program.account.otcConfig.fetch(otcConfig);
+
+    await program.methods
+      .setTokenExtensionPolicy({
+        tokenExtensionPolicy: ALLOW_CONFIDENTIAL_TRANSFER,
+      })
+      .accounts({ admin: wallet.publicKey, otcConfig })
+      .signers([wallet.payer])
+      .rpc({ commitment: COMMITMENT });
+
+    const config = await program.account.otcConfig.fetch(otcConfig);
+    assert(
+      config.tokenExtensionPolicy == ALLOW_CONFIDENTIAL_TRANSFER,
+      "token extension policy",
+    );
+
+    await program.methods
+      .setTokenExtensionPolicy({
+        tokenExtensionPolicy: initialConfig.tokenExtensionPolicy,
+      })
+      .accounts({ admin: wallet.publicKey, otcConfig })
+      .signers([wallet.payer])
+      .rpc({ commitment: COMMITMENT });
+  });
+});
@@ -0,0 +1,93 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { Program, Wallet } from "@coral-xyz/anchor";
+import { OtcMarket } from "../../target/types/otc_market";
+import { assert, expect } from "chai";
+import { Otc } from "./utils/otc";
+import { OtcTools } from "./utils/otc-tools";
+import { AccountTools } from "./utils/account-tools";
+import { Decimals } from "./config/constants";
+
+describe("Verify Escrow", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+  const program = anchor.workspace.OtcMarket as Program<OtcMarket>;
+  const connection = provider.connection;
+  const wallet = provider.wallet as Wallet;
+
+  const otc = new Otc(program, connection, wallet.payer);
+  const nativeTokenAddress = Array.from(PublicKey.default.toBytes());
+  const decimalConversionRate = 10 ** (Decimals.SOL - Decimals.SD);
+
+  let accounts: {
+    seller: Keypair;
+    offer: [PublicKey, number[]];
+    escrow: PublicKey;
+  };
+
+  // sol vaults are the escrows themselves, no token mint and program
+  const verifyEscrow = (escrows: PublicKey[]) =>
+    program.methods
+      .verifyEscrow()
+      .accounts({
+        escrowLedger: otc.deriver.escrowLedger(nativeTokenAddress),
+        tokenMint: null,
+        tokenProgram: null,
+      })
+      .remainingAccounts(
+        escrows.map((pubkey) => ({
+          pubkey,
+          isSigner: false,
+          isWritable: false,
+        })),
+      )
+      .view();
+
+  before(async () => {
+    const seller = Keypair.generate();
+    await AccountTools.topUpAccounts(otc, seller);
+
+    const offer = await OtcTools.createOffer(otc, seller);
+    accounts = { seller, offer, escrow: otc.deriver.escrow(offer[1]) };
+  });
+
+  after(async () => {
+    await AccountTools.getRemainings(
+      connection,
+      [accounts.seller],
+      wallet.publicKey,
+    );
+  });
+
+  it("should revert on InvalidEscrowVault", async () => {
+    try {
+      // a vault given twice would be counted twice
+      await verifyEscrow([accounts.escrow, accounts.escrow]);
+
+      assert(false, "should revert");
+    } catch (error: any) {
+      expect(error.simulationResponse.logs.join("\n")).to.contain(
+        "Error Code: InvalidEscrowVault",
+      );
+    }
+  });
+
+  it("should report escrow of the given vaults", async () => {
+    const offer = await program.account.offer.fetch(accounts.offer[0]);
+    const escrowLedger = await program.account.escrowLedger.fetch(
+      otc.deriver.escrowLedger(nativeTokenAddress),
+    );
+
+    // other open offers of the token are left out, so the report is partial
+    const report = await verifyEscrow([accounts.escrow]);
+
+    expect(report.tokenAddress).to.deep.equal(nativeTokenAddress);
+    assert(report.escrowedLd.eq(escrowLedger.escrowedLd), "escrowed ld");
+    assert(
+      report.balanceLd.eq(offer.srcAmountSd.muln(decimalConversionRate)),
+      "balance ld",
+    );
+    assert(report.vaults.eqn(1), "vaults");
+    assert(report.isComplete == escrowLedger.vaults.eqn(1), "is complete");
+  });
+});
@@ -0,0 +1,123 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { AnchorError, Program, Wallet } from "@coral-xyz/anchor";
+import { OtcMarket } from "../../target/types/otc_market";
+import { assert, expect } from "chai";
+import { Otc } from "./utils/otc";
+import { OtcTools } from "./utils/otc-tools";
+import { AccountTools } from "./utils/account-tools";
+import { COMMITMENT, GAS } from "./config/constants";
+
+describe("Sweep Escrow Surplus", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+  const program = anchor.workspace.OtcMarket as Program<OtcMarket>;
+  const connection = provider.connection;
+  const wallet = provider.wallet as Wallet;
+
+  const otc = new Otc(program, connection, wallet.payer);
+
+  const surplusLd = 5_000;
+
+  let accounts: {
+    otcConfig: PublicKey;
+    treasury: PublicKey;
+    seller: Keypair;
+    offer: [PublicKey, number[]];
+    escrow: PublicKey;
+  };
+
+  const sweepEscrowSurplus = (admin: Keypair) =>
+    program.methods
+      .sweepEscrowSurplus(accounts.offer[1])
+      .accounts({
+        admin: admin.publicKey,
+        otcConfig: accounts.otcConfig,
+        offer: accounts.offer[0],
+        escrow: accounts.escrow,
+        destination: accounts.treasury,
+        escrowAta: null,
+        destinationAta: null,
+        tokenMint: null,
+        associatedTokenProgram: null,
+        tokenProgram: null,
+      })
+      .signers([admin])
+      .rpc({ commitment: COMMITMENT });
+
+  before(async () => {
+    const otcConfig = otc.deriver.config();
+    const seller = Keypair.generate();
+    await AccountTools.topUpAccounts(otc, seller);
+
+    const offer = await OtcTools.createOffer(otc, seller);
+    accounts = {
+      otcConfig,
+      treasury: (await program.account.otcConfig.fetch(otcConfig)).treasury,
+      seller,
+      offer,
+      escrow: otc.deriver.escrow(offer[1]),
+    };
+
+    // a sweep smaller than the rent exemption can not open the treasury
+    await AccountTools.transferSol(
+      connection,
+      wallet.payer,
+      accounts.treasury,
+      GAS,
+    );
+    // lamports sent to the escrow directly are not owed to the offer
+    await AccountTools.transferSol(
+      connection,
+      wallet.payer,
+      accounts.escrow,
+      surplusLd,
+    );
+  });
+
+  after(async () => {
+    await AccountTools.getRemainings(
+      connection,
+      [accounts.seller],
+      wallet.publicKey,
+    );
+  });
+
+  it("should revert on Unauthorized", async () => {
+    try {
+      await sweepEscrowSurplus(accounts.seller);
+
+      assert(false, "should revert");
+    } catch (error: any) {
+      expect(error).to.be.instanceOf(AnchorError);
+      expect((error as AnchorError).error.errorCode.code).to.equal(
+        "Unauthorized",
+      );
+    }
+  });
+
+  it("should sweep escrow surplus to treasury", async () => {
+    const initialEscrowBalance = await connection.getBalance(accounts.escrow);
+    const initialTreasuryBalance = await connection.getBalance(
+      accounts.treasury,
+    );
+
+    await sweepEscrowSurplus(wallet.payer);
+
+    const escrowBalance = await connection.getBalance(accounts.escrow);
+    const treasuryBalance = await connection.getBalance(accounts.treasury);
+
+    assert(initialEscrowBalance - escrowBalance == surplusLd, "escrow balance");
+    assert(
+      treasuryBalance - initialTreasuryBalance == surplusLd,
+      "treasury balance",
+    );
+
+    // nothing is left to sweep, the offer stays fully collateralized
+    await sweepEscrowSurplus(wallet.payer);
+    assert(
+      (await connection.getBalance(accounts.escrow)) == escrowBalance,
+      "escrow balance after second sweep",
+    );
+  });
+});
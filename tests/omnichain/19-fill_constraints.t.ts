@@ -0,0 +1,159 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { AnchorError, Program, Wallet } from "@coral-xyz/anchor";
+import { OtcMarket } from "../../target/types/otc_market";
+import { assert, expect } from "chai";
+import { Otc } from "./utils/otc";
+import { OtcTools } from "./utils/otc-tools";
+import { AccountTools } from "./utils/account-tools";
+
+describe("Fill Constraints", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+  const program = anchor.workspace.OtcMarket as Program<OtcMarket>;
+  const connection = provider.connection;
+  const wallet = provider.wallet as Wallet;
+
+  const otc = new Otc(program, connection, wallet.payer);
+
+  // monochain sol offers escrow 123 sd
+  const minFillSd = new anchor.BN(100);
+
+  let accounts: {
+    otcConfig: PublicKey;
+    seller: Keypair;
+    buyer: Keypair;
+    offer: [PublicKey, number[]];
+    allOrNothingOffer: [PublicKey, number[]];
+  };
+
+  before(async () => {
+    const seller = Keypair.generate();
+    const buyer = Keypair.generate();
+
+    // one top up per offer
+    await AccountTools.topUpAccounts(otc, seller, buyer);
+    await AccountTools.topUpAccounts(otc, seller);
+
+    accounts = {
+      otcConfig: otc.deriver.config(),
+      seller,
+      buyer,
+      offer: await OtcTools.createOffer(
+        otc,
+        seller,
+        undefined,
+        null,
+        undefined,
+        { minFillSd },
+      ),
+      allOrNothingOffer: await OtcTools.createOffer(
+        otc,
+        seller,
+        undefined,
+        null,
+        undefined,
+        { allOrNothing: true },
+      ),
+    };
+  });
+
+  after(async () => {
+    await AccountTools.getRemainings(
+      connection,
+      [accounts.seller, accounts.buyer],
+      wallet.publicKey,
+    );
+  });
+
+  describe("Create Offer", () => {
+    it("should revert on InvalidMinFill", async () => {
+      try {
+        // above the escrowed amount
+        await OtcTools.createNativeOffer(otc, accounts.seller, {
+          minFillSd: new anchor.BN(124),
+        });
+
+        assert(false, "should revert");
+      } catch (error: any) {
+        expect(error).to.be.instanceOf(AnchorError);
+        expect((error as AnchorError).error.errorCode.code).to.equal(
+          "InvalidMinFill",
+        );
+      }
+    });
+  });
+
+  describe("Accept Offer", () => {
+    it("should revert below min fill on InvalidFillAmount", async () => {
+      try {
+        await OtcTools.acceptOffer(otc, accounts.offer, accounts.buyer, {
+          srcAmountSd: minFillSd.subn(1),
+        });
+
+        assert(false, "should revert");
+      } catch (error: any) {
+        expect(error).to.be.instanceOf(AnchorError);
+        expect((error as AnchorError).error.errorCode.code).to.equal(
+          "InvalidFillAmount",
+        );
+      }
+    });
+
+    it("should revert all or nothing partial fill", async () => {
+      try {
+        await OtcTools.acceptOffer(
+          otc,
+          accounts.allOrNothingOffer,
+          accounts.buyer,
+          { srcAmountSd: new anchor.BN(1) },
+        );
+
+        assert(false, "should revert");
+      } catch (error: any) {
+        expect(error).to.be.instanceOf(AnchorError);
+        expect((error as AnchorError).error.errorCode.code).to.equal(
+          "InvalidFillAmount",
+        );
+      }
+    });
+
+    it("should accept min fill", async () => {
+      const initialOffer = await program.account.offer.fetch(accounts.offer[0]);
+
+      await OtcTools.acceptOffer(otc, accounts.offer, accounts.buyer, {
+        srcAmountSd: minFillSd,
+      });
+
+      const offer = await program.account.offer.fetch(accounts.offer[0]);
+      assert(
+        offer.srcAmountSd.eq(initialOffer.srcAmountSd.sub(minFillSd)),
+        "src amount sd",
+      );
+      assert(offer.srcAmountSd.lt(minFillSd), "remainder below min fill");
+    });
+
+    it("should accept final remainder below min fill", async () => {
+      await OtcTools.acceptOffer(otc, accounts.offer, accounts.buyer);
+
+      assert(
+        (await connection.getAccountInfo(accounts.offer[0])) === null,
+        "offer closed",
+      );
+    });
+
+    it("should accept all or nothing offer in full", async () => {
+      await OtcTools.acceptOffer(
+        otc,
+        accounts.allOrNothingOffer,
+        accounts.buyer,
+      );
+
+      assert(
+        (await connection.getAccountInfo(accounts.allOrNothingOffer[0])) ===
+          null,
+        "offer closed",
+      );
+    });
+  });
+});
@@ -0,0 +1,134 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { AnchorError, Program, Wallet } from "@coral-xyz/anchor";
+import { OtcMarket } from "../../target/types/otc_market";
+import { assert, expect } from "chai";
+import { Otc } from "./utils/otc";
+import { OtcTools } from "./utils/otc-tools";
+import { AccountTools } from "./utils/account-tools";
+import {
+  getAcceptOfferParams,
+  getQuoteAcceptOfferAccounts,
+} from "../helpers/accept_offer";
+
+describe("Allowed Buyer", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+  const program = anchor.workspace.OtcMarket as Program<OtcMarket>;
+  const connection = provider.connection;
+  const wallet = provider.wallet as Wallet;
+
+  const otc = new Otc(program, connection, wallet.payer);
+
+  let accounts: {
+    otcConfig: PublicKey;
+    seller: Keypair;
+    buyer: Keypair;
+    stranger: Keypair;
+    offer: [PublicKey, number[]];
+  };
+
+  before(async () => {
+    const seller = Keypair.generate();
+    const buyer = Keypair.generate();
+    const stranger = Keypair.generate();
+
+    await AccountTools.topUpAccounts(otc, seller, buyer);
+    await AccountTools.topUpAccounts(otc, seller, stranger);
+
+    accounts = {
+      otcConfig: otc.deriver.config(),
+      seller,
+      buyer,
+      stranger,
+      offer: await OtcTools.createOffer(
+        otc,
+        seller,
+        undefined,
+        null,
+        undefined,
+        { allowedBuyer: Array.from(buyer.publicKey.toBytes()) },
+      ),
+    };
+  });
+
+  after(async () => {
+    await AccountTools.getRemainings(
+      connection,
+      [accounts.seller, accounts.buyer, accounts.stranger],
+      wallet.publicKey,
+    );
+  });
+
+  describe("Create Offer", () => {
+    it("should revert on InvalidAllowedBuyer", async () => {
+      try {
+        await OtcTools.createNativeOffer(otc, accounts.seller, {
+          allowedBuyer: Array.from(PublicKey.default.toBytes()),
+        });
+
+        assert(false, "should revert");
+      } catch (error: any) {
+        expect(error).to.be.instanceOf(AnchorError);
+        expect((error as AnchorError).error.errorCode.code).to.equal(
+          "InvalidAllowedBuyer",
+        );
+      }
+    });
+  });
+
+  describe("Quote Accept Offer", () => {
+    it("should revert on OnlyAllowedBuyer", async () => {
+      const stranger = Array.from(accounts.stranger.publicKey.toBytes());
+
+      try {
+        await program.methods
+          .quoteAcceptOffer(
+            stranger,
+            getAcceptOfferParams(accounts.offer[1], new anchor.BN(1), stranger),
+            false,
+          )
+          .accounts(
+            await getQuoteAcceptOfferAccounts(
+              program,
+              { otcConfig: accounts.otcConfig },
+              accounts.offer[0],
+            ),
+          )
+          .view();
+
+        assert(false, "should revert");
+      } catch (error: any) {
+        expect(error.simulationResponse.logs.join("\n")).to.contain(
+          "Error Code: OnlyAllowedBuyer",
+        );
+      }
+    });
+  });
+
+  describe("Accept Offer", () => {
+    it("should revert on OnlyAllowedBuyer", async () => {
+      try {
+        await OtcTools.acceptOffer(otc, accounts.offer, accounts.stranger, {
+          srcAmountSd: new anchor.BN(1),
+        });
+
+        assert(false, "should revert");
+      } catch (error: any) {
+        expect(error).to.be.instanceOf(AnchorError);
+        expect((error as AnchorError).error.errorCode.code).to.equal(
+          "OnlyAllowedBuyer",
+        );
+      }
+    });
+
+    it("should accept as allowed buyer", async () => {
+      await OtcTools.acceptOffer(otc, accounts.offer, accounts.buyer);
+
+      assert(
+        (await connection.getAccountInfo(accounts.offer[0])) === null,
+        "offer closed",
+      );
+    });
+  });
+});
@@ -0,0 +1,98 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { AnchorError, Program, Wallet } from "@coral-xyz/anchor";
+import { OtcMarket } from "../../target/types/otc_market";
+import { assert, expect } from "chai";
+import { Otc } from "./utils/otc";
+import { OtcTools } from "./utils/otc-tools";
+import { AccountTools } from "./utils/account-tools";
+import { getAcceptOfferParams } from "../helpers/accept_offer";
+
+describe("Slippage", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+  const program = anchor.workspace.OtcMarket as Program<OtcMarket>;
+  const connection = provider.connection;
+  const wallet = provider.wallet as Wallet;
+
+  const otc = new Otc(program, connection, wallet.payer);
+
+  const srcAmountSd = new anchor.BN(23);
+
+  let accounts: {
+    otcConfig: PublicKey;
+    seller: Keypair;
+    buyer: Keypair;
+    offer: [PublicKey, number[]];
+  };
+
+  // dst amount the buyer is quoted for srcAmountSd, fees included
+  const quoteDstAmountLd = async () => {
+    const [receipt] = await otc.quoteAcceptOffer(
+      getAcceptOfferParams(
+        accounts.offer[1],
+        srcAmountSd,
+        Array.from(accounts.buyer.publicKey.toBytes()),
+      ),
+      accounts.buyer,
+    );
+
+    return receipt.dstAmountLd;
+  };
+
+  before(async () => {
+    const seller = Keypair.generate();
+    const buyer = Keypair.generate();
+
+    await AccountTools.topUpAccounts(otc, seller, buyer);
+
+    accounts = {
+      otcConfig: otc.deriver.config(),
+      seller,
+      buyer,
+      offer: await OtcTools.createOffer(otc, seller),
+    };
+  });
+
+  after(async () => {
+    await AccountTools.getRemainings(
+      connection,
+      [accounts.seller, accounts.buyer],
+      wallet.publicKey,
+    );
+  });
+
+  it("should revert on SlippageExceeded", async () => {
+    const dstAmountLd = await quoteDstAmountLd();
+
+    try {
+      await OtcTools.acceptOffer(otc, accounts.offer, accounts.buyer, {
+        srcAmountSd,
+        maxDstAmountLd: dstAmountLd.subn(1),
+      });
+
+      assert(false, "should revert");
+    } catch (error: any) {
+      expect(error).to.be.instanceOf(AnchorError);
+      expect((error as AnchorError).error.errorCode.code).to.equal(
+        "SlippageExceeded",
+      );
+    }
+  });
+
+  it("should accept at exactly the quoted amount", async () => {
+    const initialOffer = await program.account.offer.fetch(accounts.offer[0]);
+    const dstAmountLd = await quoteDstAmountLd();
+
+    await OtcTools.acceptOffer(otc, accounts.offer, accounts.buyer, {
+      srcAmountSd,
+      maxDstAmountLd: dstAmountLd,
+    });
+
+    const offer = await program.account.offer.fetch(accounts.offer[0]);
+    assert(
+      offer.srcAmountSd.eq(initialOffer.srcAmountSd.sub(srcAmountSd)),
+      "src amount sd",
+    );
+  });
+});
@@ -0,0 +1,251 @@
+import * as anchor from "@coral-xyz/anchor";
+import {
+  Keypair,
+  PublicKey,
+  sendAndConfirmTransaction,
+  SystemProgram,
+  Transaction,
+} from "@solana/web3.js";
+import { Program, Wallet } from "@coral-xyz/anchor";
+import { OtcMarket } from "../../target/types/otc_market";
+import { assert } from "chai";
+import {
+  createInitializeMintInstruction,
+  createInitializeTransferFeeConfigInstruction,
+  createMint,
+  ExtensionType,
+  getAssociatedTokenAddressSync,
+  getMintLen,
+  getOrCreateAssociatedTokenAccount,
+  mintTo,
+  TOKEN_2022_PROGRAM_ID,
+} from "@solana/spl-token";
+import { Otc } from "./utils/otc";
+import { OtcTools } from "./utils/otc-tools";
+import { AccountTools } from "./utils/account-tools";
+import { AmountsLD, COMMITMENT, Decimals, GAS } from "./config/constants";
+import { getBalance } from "../helpers/spl";
+
+describe("Token-2022", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+  const program = anchor.workspace.OtcMarket as Program<OtcMarket>;
+  const connection = provider.connection;
+  const wallet = provider.wallet as Wallet;
+
+  const otc = new Otc(program, connection, wallet.payer);
+
+  let accounts: {
+    seller: Keypair;
+    buyer: Keypair;
+  };
+
+  // token-2022 mint withholding transferFeeBps of every transfer, rounded up
+  const createTransferFeeMint = async (transferFeeBps: number) => {
+    const mint = Keypair.generate();
+    const mintLen = getMintLen([ExtensionType.TransferFeeConfig]);
+
+    const tx = new Transaction().add(
+      SystemProgram.createAccount({
+        fromPubkey: wallet.publicKey,
+        newAccountPubkey: mint.publicKey,
+        space: mintLen,
+        lamports: await connection.getMinimumBalanceForRentExemption(mintLen),
+        programId: TOKEN_2022_PROGRAM_ID,
+      }),
+      createInitializeTransferFeeConfigInstruction(
+        mint.publicKey,
+        wallet.publicKey,
+        wallet.publicKey,
+        transferFeeBps,
+        BigInt(AmountsLD.SPL),
+        TOKEN_2022_PROGRAM_ID,
+      ),
+      createInitializeMintInstruction(
+        mint.publicKey,
+        Decimals.SPL,
+        wallet.publicKey,
+        null,
+        TOKEN_2022_PROGRAM_ID,
+      ),
+    );
+    await sendAndConfirmTransaction(connection, tx, [wallet.payer, mint]);
+
+    return mint.publicKey;
+  };
+
+  // funds the seller with AmountsLD.SPL of a token-2022 mint
+  const mintToSeller = async (mint: PublicKey) => {
+    const sellerAta = await getOrCreateAssociatedTokenAccount(
+      connection,
+      wallet.payer,
+      mint,
+      accounts.seller.publicKey,
+      false,
+      COMMITMENT,
+      undefined,
+      TOKEN_2022_PROGRAM_ID,
+    );
+    await mintTo(
+      connection,
+      wallet.payer,
+      mint,
+      sellerAta.address,
+      wallet.payer,
+      AmountsLD.SPL,
+      [],
+      undefined,
+      TOKEN_2022_PROGRAM_ID,
+    );
+  };
+
+  before(async () => {
+    const seller = Keypair.generate();
+    const buyer = Keypair.generate();
+
+    await AccountTools.transferSol(
+      connection,
+      wallet.payer,
+      seller.publicKey,
+      GAS,
+    );
+    await AccountTools.transferSol(
+      connection,
+      wallet.payer,
+      buyer.publicKey,
+      GAS,
+    );
+
+    accounts = { seller, buyer };
+  });
+
+  after(async () => {
+    await AccountTools.getRemainings(
+      connection,
+      [accounts.seller, accounts.buyer],
+      wallet.publicKey,
+    );
+  });
+
+  describe("Token-2022 Mint", () => {
+    let mint: PublicKey;
+    let offer: [PublicKey, number[]];
+
+    before(async () => {
+      mint = await createMint(
+        connection,
+        wallet.payer,
+        wallet.publicKey,
+        null,
+        Decimals.SPL,
+        undefined,
+        undefined,
+        TOKEN_2022_PROGRAM_ID,
+      );
+      await mintToSeller(mint);
+    });
+
+    it("should create offer", async () => {
+      offer = await OtcTools.createOffer(otc, accounts.seller, undefined, mint);
+
+      const offerAccount = await program.account.offer.fetch(offer[0]);
+      assert(
+        new PublicKey(offerAccount.srcTokenProgram).equals(
+          TOKEN_2022_PROGRAM_ID,
+        ),
+        "src token program",
+      );
+
+      // spl decimals equal the shared decimals
+      const escrowAta = getAssociatedTokenAddressSync(
+        mint,
+        otc.deriver.escrow(offer[1]),
+        true,
+        TOKEN_2022_PROGRAM_ID,
+      );
+      assert(
+        (await getBalance(connection, escrowAta, TOKEN_2022_PROGRAM_ID)) ==
+          offerAccount.srcAmountSd.toNumber(),
+        "escrowed amount",
+      );
+    });
+
+    it("should accept offer", async () => {
+      const offerAccount = await program.account.offer.fetch(offer[0]);
+
+      await OtcTools.acceptOffer(otc, offer, accounts.buyer);
+
+      const buyerAta = getAssociatedTokenAddressSync(
+        mint,
+        accounts.buyer.publicKey,
+        false,
+        TOKEN_2022_PROGRAM_ID,
+      );
+      assert(
+        (await getBalance(connection, buyerAta, TOKEN_2022_PROGRAM_ID)) ==
+          offerAccount.srcAmountSd.toNumber(),
+        "buyer amount",
+      );
+      assert(
+        (await connection.getAccountInfo(offer[0])) === null,
+        "offer closed",
+      );
+    });
+  });
+
+  describe("Transfer Fee Mint", () => {
+    // 1%, spl decimals equal the shared decimals so ld and sd amounts match
+    const transferFeeBps = 100;
+    const transferFee = (amount: number) =>
+      Math.ceil((amount * transferFeeBps) / 10_000);
+
+    let mint: PublicKey;
+    let offer: [PublicKey, number[]];
+
+    before(async () => {
+      mint = await createTransferFeeMint(transferFeeBps);
+      await mintToSeller(mint);
+    });
+
+    it("should create offer net of the transfer fee", async () => {
+      const otcConfig = await program.account.otcConfig.fetch(
+        otc.deriver.config(),
+      );
+      const makerFee = Math.ceil(
+        (AmountsLD.SPL * otcConfig.makerFeeBps) / 10_000,
+      );
+      const toEscrow = AmountsLD.SPL - makerFee;
+
+      offer = await OtcTools.createOffer(otc, accounts.seller, undefined, mint);
+
+      const offerAccount = await program.account.offer.fetch(offer[0]);
+      assert(
+        offerAccount.srcAmountSd.toNumber() == toEscrow - transferFee(toEscrow),
+        "escrowed amount",
+      );
+    });
+
+    it("should accept offer net of the transfer fee", async () => {
+      const offerAccount = await program.account.offer.fetch(offer[0]);
+      const escrowed = offerAccount.srcAmountSd.toNumber();
+
+      await OtcTools.acceptOffer(otc, offer, accounts.buyer);
+
+      const buyerAta = getAssociatedTokenAddressSync(
+        mint,
+        accounts.buyer.publicKey,
+        false,
+        TOKEN_2022_PROGRAM_ID,
+      );
+      assert(
+        (await getBalance(connection, buyerAta, TOKEN_2022_PROGRAM_ID)) ==
+          escrowed - transferFee(escrowed),
+        "buyer amount",
+      );
+      assert(
+        (await connection.getAccountInfo(offer[0])) === null,
+        "offer closed",
+      );
+    });
+  });
+});
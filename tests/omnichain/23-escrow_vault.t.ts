@@ -0,0 +1,207 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { Program, Wallet } from "@coral-xyz/anchor";
+import { OtcMarket } from "../../target/types/otc_market";
+import { assert } from "chai";
+import {
+  createMint,
+  getAssociatedTokenAddressSync,
+  getOrCreateAssociatedTokenAccount,
+  mintTo,
+  TOKEN_PROGRAM_ID,
+} from "@solana/spl-token";
+import { Otc } from "./utils/otc";
+import { OtcTools } from "./utils/otc-tools";
+import { AccountTools } from "./utils/account-tools";
+import {
+  AmountsLD,
+  COMMITMENT,
+  Decimals,
+  GAS,
+  SOLANA_EID,
+} from "./config/constants";
+import { getBalance } from "../helpers/spl";
+
+// rounding dust can't be created through the program, it is minted straight
+// into the escrow token account here
+describe("Escrow Vault", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+  const program = anchor.workspace.OtcMarket as Program<OtcMarket>;
+  const connection = provider.connection;
+  const wallet = provider.wallet as Wallet;
+
+  const otc = new Otc(program, connection, wallet.payer);
+
+  const dustLd = 7;
+
+  let accounts: {
+    otcConfig: PublicKey;
+    seller: Keypair;
+    sellerAta: PublicKey;
+    buyer: Keypair;
+    srcTokenMint: PublicKey;
+  };
+
+  // spl offer with dustLd sitting in its escrow token account
+  const createOfferWithDust = async (): Promise<[PublicKey, number[]]> => {
+    await mintTo(
+      connection,
+      wallet.payer,
+      accounts.srcTokenMint,
+      accounts.sellerAta,
+      wallet.payer,
+      AmountsLD.SPL,
+    );
+    const offer = await OtcTools.createOffer(
+      otc,
+      accounts.seller,
+      undefined,
+      accounts.srcTokenMint,
+    );
+    await mintTo(
+      connection,
+      wallet.payer,
+      accounts.srcTokenMint,
+      getEscrowAta(offer),
+      wallet.payer,
+      dustLd,
+    );
+
+    return offer;
+  };
+
+  const getEscrowAta = (offer: [PublicKey, number[]]) =>
+    getAssociatedTokenAddressSync(
+      accounts.srcTokenMint,
+      otc.deriver.escrow(offer[1]),
+      true,
+    );
+
+  const assertVaultClosed = async (offer: [PublicKey, number[]]) => {
+    assert(
+      (await connection.getAccountInfo(otc.deriver.escrow(offer[1]))) ===
+        null,
+      "escrow closed",
+    );
+    assert(
+      (await connection.getAccountInfo(getEscrowAta(offer))) === null,
+      "escrow ata closed",
+    );
+    assert(
+      (await connection.getAccountInfo(offer[0])) === null,
+      "offer closed",
+    );
+  };
+
+  before(async () => {
+    const seller = Keypair.generate();
+    const buyer = Keypair.generate();
+
+    await AccountTools.transferSol(
+      connection,
+      wallet.payer,
+      seller.publicKey,
+      GAS,
+    );
+    await AccountTools.transferSol(
+      connection,
+      wallet.payer,
+      buyer.publicKey,
+      GAS,
+    );
+
+    const srcTokenMint = await createMint(
+      connection,
+      wallet.payer,
+      wallet.publicKey,
+      null,
+      Decimals.SPL,
+    );
+    const sellerAta = (
+      await getOrCreateAssociatedTokenAccount(
+        connection,
+        wallet.payer,
+        srcTokenMint,
+        seller.publicKey,
+      )
+    ).address;
+
+    accounts = {
+      otcConfig: otc.deriver.config(),
+      seller,
+      sellerAta,
+      buyer,
+      srcTokenMint,
+    };
+  });
+
+  after(async () => {
+    await AccountTools.getRemainings(
+      connection,
+      [accounts.seller, accounts.buyer],
+      wallet.publicKey,
+    );
+  });
+
+  it("should close vault on full fill and pay dust to seller", async () => {
+    const offer = await createOfferWithDust();
+    const initialSellerBalance = await getBalance(
+      connection,
+      accounts.sellerAta,
+    );
+
+    await OtcTools.acceptOffer(otc, offer, accounts.buyer);
+
+    await assertVaultClosed(offer);
+    assert(
+      (await getBalance(connection, accounts.sellerAta)) ==
+        initialSellerBalance.valueOf() + dustLd,
+      "seller received dust",
+    );
+  });
+
+  it("should close vault on cancel and pay dust to seller", async () => {
+    const offer = await createOfferWithDust();
+    const offerAccount = await program.account.offer.fetch(offer[0]);
+    const initialSellerBalance = await getBalance(
+      connection,
+      accounts.sellerAta,
+    );
+
+    await program.methods
+      .cancelOffer(
+        offer[1],
+        { nativeFee: new anchor.BN(0), lzTokenFee: new anchor.BN(0) },
+        Buffer.from([]),
+      )
+      .accounts({
+        seller: accounts.seller.publicKey,
+        otcConfig: accounts.otcConfig,
+        offer: offer[0],
+        eidPause: otc.deriver.pause(SOLANA_EID),
+        srcSellerAta: accounts.sellerAta,
+        srcEscrowAta: getEscrowAta(offer),
+        escrow: otc.deriver.escrow(offer[1]),
+        escrowLedger: otc.deriver.escrowLedger(
+          Array.from(accounts.srcTokenMint.toBytes()),
+        ),
+        srcTokenMint: accounts.srcTokenMint,
+        peer: null,
+        enforcedOptions: null,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([accounts.seller])
+      .rpc({ commitment: COMMITMENT });
+
+    await assertVaultClosed(offer);
+    // spl decimals equal the shared decimals
+    assert(
+      (await getBalance(connection, accounts.sellerAta)) ==
+        initialSellerBalance.valueOf() +
+          offerAccount.srcAmountSd.toNumber() +
+          dustLd,
+      "seller received escrow and dust",
+    );
+  });
+});
@@ -0,0 +1,181 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { AnchorError, Program, Wallet } from "@coral-xyz/anchor";
+import { OtcMarket } from "../../target/types/otc_market";
+import { assert, expect } from "chai";
+import { Otc } from "./utils/otc";
+import { COMMITMENT } from "./config/constants";
+
+describe("Admin", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+  const program = anchor.workspace.OtcMarket as Program<OtcMarket>;
+  const connection = provider.connection;
+  const wallet = provider.wallet as Wallet;
+
+  const otc = new Otc(program, connection, wallet.payer);
+
+  let accounts: {
+    otcConfig: PublicKey;
+    newAdmin: Keypair;
+    stranger: Keypair;
+  };
+
+  before(async () => {
+    accounts = {
+      otcConfig: otc.deriver.config(),
+      newAdmin: Keypair.generate(),
+      stranger: Keypair.generate(),
+    };
+  });
+
+  describe("Propose Admin", () => {
+    it("should revert on Unauthorized", async () => {
+      try {
+        await program.methods
+          .proposeAdmin({ pendingAdmin: accounts.stranger.publicKey })
+          .accounts({
+            admin: accounts.stranger.publicKey,
+            otcConfig: accounts.otcConfig,
+          })
+          .signers([accounts.stranger])
+          .rpc({ commitment: COMMITMENT });
+
+        assert(false, "should revert");
+      } catch (error: any) {
+        expect(error).to.be.instanceOf(AnchorError);
+        expect((error as AnchorError).error.errorCode.code).to.equal(
+          "Unauthorized",
+        );
+      }
+    });
+
+    it("should propose and revoke admin", async () => {
+      await program.methods
+        .proposeAdmin({ pendingAdmin: accounts.newAdmin.publicKey })
+        .accounts({
+          admin: wallet.publicKey,
+          otcConfig: accounts.otcConfig,
+        })
+        .signers([wallet.payer])
+        .rpc({ commitment: COMMITMENT });
+
+      let config = await program.account.otcConfig.fetch(accounts.otcConfig);
+      assert(
+        config.pendingAdmin.equals(accounts.newAdmin.publicKey),
+        "pending admin",
+      );
+      assert(config.admin.equals(wallet.publicKey), "admin unchanged");
+
+      await program.methods
+        .proposeAdmin({ pendingAdmin: null })
+        .accounts({
+          admin: wallet.publicKey,
+          otcConfig: accounts.otcConfig,
+        })
+        .signers([wallet.payer])
+        .rpc({ commitment: COMMITMENT });
+
+      config = await program.account.otcConfig.fetch(accounts.otcConfig);
+      assert(config.pendingAdmin === null, "revoked");
+    });
+  });
+
+  describe("Accept Admin", () => {
+    it("should revert on Unauthorized", async () => {
+      await program.methods
+        .proposeAdmin({ pendingAdmin: accounts.newAdmin.publicKey })
+        .accounts({
+          admin: wallet.publicKey,
+          otcConfig: accounts.otcConfig,
+        })
+        .signers([wallet.payer])
+        .rpc({ commitment: COMMITMENT });
+
+      try {
+        await otc.acceptAdmin(accounts.stranger);
+
+        assert(false, "should revert");
+      } catch (error: any) {
+        expect(error).to.be.instanceOf(AnchorError);
+        expect((error as AnchorError).error.errorCode.code).to.equal(
+          "Unauthorized",
+        );
+      }
+    });
+
+    it("should accept admin and hand it back", async () => {
+      await otc.acceptAdmin(accounts.newAdmin);
+
+      let config = await program.account.otcConfig.fetch(accounts.otcConfig);
+      assert(config.admin.equals(accounts.newAdmin.publicKey), "new admin");
+      assert(config.pendingAdmin === null, "proposal consumed");
+
+      // restore the admin for the rest of the suite
+      await program.methods
+        .proposeAdmin({ pendingAdmin: wallet.publicKey })
+        .accounts({
+          admin: accounts.newAdmin.publicKey,
+          otcConfig: accounts.otcConfig,
+        })
+        .signers([accounts.newAdmin])
+        .rpc({ commitment: COMMITMENT });
+      await otc.acceptAdmin(wallet.payer);
+
+      config = await program.account.otcConfig.fetch(accounts.otcConfig);
+      assert(config.admin.equals(wallet.publicKey), "admin restored");
+    });
+  });
+
+  describe("Set Role", () => {
+    it("should revert on Unauthorized", async () => {
+      try {
+        await program.methods
+          .setRole({
+            role: { pauser: {} },
+            account: accounts.stranger.publicKey,
+          })
+          .accounts({
+            admin: accounts.stranger.publicKey,
+            otcConfig: accounts.otcConfig,
+          })
+          .signers([accounts.stranger])
+          .rpc({ commitment: COMMITMENT });
+
+        assert(false, "should revert");
+      } catch (error: any) {
+        expect(error).to.be.instanceOf(AnchorError);
+        expect((error as AnchorError).error.errorCode.code).to.equal(
+          "Unauthorized",
+        );
+      }
+    });
+
+    it("should set role", async () => {
+      await program.methods
+        .setRole({ role: { pauser: {} }, account: accounts.newAdmin.publicKey })
+        .accounts({
+          admin: wallet.publicKey,
+          otcConfig: accounts.otcConfig,
+        })
+        .signers([wallet.payer])
+        .rpc({ commitment: COMMITMENT });
+
+      let config = await program.account.otcConfig.fetch(accounts.otcConfig);
+      assert(config.pauser.equals(accounts.newAdmin.publicKey), "pauser");
+      assert(config.feeManager.equals(wallet.publicKey), "other roles kept");
+
+      await program.methods
+        .setRole({ role: { pauser: {} }, account: wallet.publicKey })
+        .accounts({
+          admin: wallet.publicKey,
+          otcConfig: accounts.otcConfig,
+        })
+        .signers([wallet.payer])
+        .rpc({ commitment: COMMITMENT });
+
+      config = await program.account.otcConfig.fetch(accounts.otcConfig);
+      assert(config.pauser.equals(wallet.publicKey), "pauser restored");
+    });
+  });
+});
@@ -0,0 +1,109 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { AnchorError, Program, Wallet } from "@coral-xyz/anchor";
+import { OtcMarket } from "../../target/types/otc_market";
+import { assert, expect } from "chai";
+import { Otc } from "./utils/otc";
+import { COMMITMENT } from "./config/constants";
+
+describe("Set Treasury", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+  const program = anchor.workspace.OtcMarket as Program<OtcMarket>;
+  const connection = provider.connection;
+  const wallet = provider.wallet as Wallet;
+
+  const otc = new Otc(program, connection, wallet.payer);
+
+  let accounts: {
+    otcConfig: PublicKey;
+    treasury: PublicKey;
+    stranger: Keypair;
+  };
+
+  before(async () => {
+    const otcConfig = otc.deriver.config();
+    accounts = {
+      otcConfig,
+      treasury: (await program.account.otcConfig.fetch(otcConfig)).treasury,
+      stranger: Keypair.generate(),
+    };
+  });
+
+  it("should revert on Unauthorized", async () => {
+    try {
+      await program.methods
+        .setTreasury({ treasury: accounts.stranger.publicKey })
+        .accounts({
+          admin: accounts.stranger.publicKey,
+          otcConfig: accounts.otcConfig,
+        })
+        .signers([accounts.stranger])
+        .rpc({ commitment: COMMITMENT });
+
+      assert(false, "should revert");
+    } catch (error: any) {
+      expect(error).to.be.instanceOf(AnchorError);
+      expect((error as AnchorError).error.errorCode.code).to.equal(
+        "Unauthorized",
+      );
+    }
+  });
+
+  it("should revert on InvalidTreasury", async () => {
+    // fees already accrue in the vault, it is never a withdrawal destination
+    const invalidTreasuries = [
+      PublicKey.default,
+      accounts.otcConfig,
+      otc.deriver.treasury(),
+    ];
+
+    for (const invalidTreasury of invalidTreasuries) {
+      try {
+        await program.methods
+          .setTreasury({ treasury: invalidTreasury })
+          .accounts({
+            admin: wallet.publicKey,
+            otcConfig: accounts.otcConfig,
+          })
+          .signers([wallet.payer])
+          .rpc({ commitment: COMMITMENT });
+
+        assert(false, "should revert");
+      } catch (error: any) {
+        expect(error).to.be.instanceOf(AnchorError);
+        expect((error as AnchorError).error.errorCode.code).to.equal(
+          "InvalidTreasury",
+        );
+      }
+    }
+  });
+
+  it("should set treasury", async () => {
+    const newTreasury = Keypair.generate().publicKey;
+
+    await program.methods
+      .setTreasury({ treasury: newTreasury })
+      .accounts({
+        admin: wallet.publicKey,
+        otcConfig: accounts.otcConfig,
+      })
+      .signers([wallet.payer])
+      .rpc({ commitment: COMMITMENT });
+
+    let config = await program.account.otcConfig.fetch(accounts.otcConfig);
+    assert(config.treasury.equals(newTreasury), "new treasury");
+
+    await program.methods
+      .setTreasury({ treasury: accounts.treasury })
+      .accounts({
+        admin: wallet.publicKey,
+        otcConfig: accounts.otcConfig,
+      })
+      .signers([wallet.payer])
+      .rpc({ commitment: COMMITMENT });
+
+    config = await program.account.otcConfig.fetch(accounts.otcConfig);
+    assert(config.treasury.equals(accounts.treasury), "treasury restored");
+  });
+});
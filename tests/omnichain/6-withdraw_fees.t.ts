@@ -0,0 +1,160 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { AnchorError, Program, Wallet } from "@coral-xyz/anchor";
+import { OtcMarket } from "../../target/types/otc_market";
+import { assert, expect } from "chai";
+import { Otc } from "./utils/otc";
+import { OtcTools } from "./utils/otc-tools";
+import { AccountTools } from "./utils/account-tools";
+import { COMMITMENT, GAS } from "./config/constants";
+
+describe("Withdraw Fees", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+  const program = anchor.workspace.OtcMarket as Program<OtcMarket>;
+  const connection = provider.connection;
+  const wallet = provider.wallet as Wallet;
+
+  const otc = new Otc(program, connection, wallet.payer);
+  const nativeTokenAddress = Array.from(PublicKey.default.toBytes());
+
+  let accounts: {
+    otcConfig: PublicKey;
+    treasury: PublicKey;
+    seller: Keypair;
+    buyer: Keypair;
+  };
+
+  before(async () => {
+    const otcConfig = otc.deriver.config();
+    accounts = {
+      otcConfig,
+      treasury: (await program.account.otcConfig.fetch(otcConfig)).treasury,
+      seller: Keypair.generate(),
+      buyer: Keypair.generate(),
+    };
+
+    await AccountTools.topUpAccounts(otc, accounts.seller);
+    await AccountTools.transferSol(
+      connection,
+      wallet.payer,
+      accounts.buyer.publicKey,
+      GAS,
+    );
+    // a withdrawal smaller than the rent exemption can not open the treasury
+    await AccountTools.transferSol(
+      connection,
+      wallet.payer,
+      accounts.treasury,
+      GAS,
+    );
+
+    // accrue a taker fee in sol
+    const offer = await OtcTools.createOffer(otc, accounts.seller);
+    await OtcTools.acceptOffer(otc, offer, accounts.buyer);
+  });
+
+  after(async () => {
+    await AccountTools.getRemainings(
+      connection,
+      [accounts.seller, accounts.buyer],
+      wallet.publicKey,
+    );
+  });
+
+  it("should revert on Unauthorized", async () => {
+    const stranger = Keypair.generate();
+
+    try {
+      await program.methods
+        .withdrawFees()
+        .accounts({
+          feeManager: stranger.publicKey,
+          otcConfig: accounts.otcConfig,
+          treasury: otc.deriver.treasury(),
+          feeLedger: otc.deriver.feeLedger(nativeTokenAddress),
+          destination: accounts.treasury,
+          treasuryAta: null,
+          destinationAta: null,
+          tokenMint: null,
+          associatedTokenProgram: null,
+          tokenProgram: null,
+        })
+        .signers([stranger])
+        .rpc({ commitment: COMMITMENT });
+
+      assert(false, "should revert");
+    } catch (error: any) {
+      expect(error).to.be.instanceOf(AnchorError);
+      expect((error as AnchorError).error.errorCode.code).to.equal(
+        "Unauthorized",
+      );
+    }
+  });
+
+  it("should revert on InvalidTreasury", async () => {
+    try {
+      await program.methods
+        .withdrawFees()
+        .accounts({
+          feeManager: wallet.publicKey,
+          otcConfig: accounts.otcConfig,
+          treasury: otc.deriver.treasury(),
+          feeLedger: otc.deriver.feeLedger(nativeTokenAddress),
+          destination: wallet.publicKey,
+          treasuryAta: null,
+          destinationAta: null,
+          tokenMint: null,
+          associatedTokenProgram: null,
+          tokenProgram: null,
+        })
+        .signers([wallet.payer])
+        .rpc({ commitment: COMMITMENT });
+
+      assert(false, "should revert");
+    } catch (error: any) {
+      expect(error).to.be.instanceOf(AnchorError);
+      expect((error as AnchorError).error.errorCode.code).to.equal(
+        "InvalidTreasury",
+      );
+    }
+  });
+
+  it("should withdraw sol fees", async () => {
+    const feeLedger = otc.deriver.feeLedger(nativeTokenAddress);
+
+    const initialLedger = await program.account.feeLedger.fetch(feeLedger);
+    const owedLd = initialLedger.collectedLd.sub(initialLedger.withdrawnLd);
+    assert(owedLd.gtn(0), "fees accrued");
+
+    const initialTreasuryBalance = await connection.getBalance(
+      accounts.treasury,
+    );
+
+    await program.methods
+      .withdrawFees()
+      .accounts({
+        feeManager: wallet.publicKey,
+        otcConfig: accounts.otcConfig,
+        treasury: otc.deriver.treasury(),
+        feeLedger,
+        destination: accounts.treasury,
+        treasuryAta: null,
+        destinationAta: null,
+        tokenMint: null,
+        associatedTokenProgram: null,
+        tokenProgram: null,
+      })
+      .signers([wallet.payer])
+      .rpc({ commitment: COMMITMENT });
+
+    const ledger = await program.account.feeLedger.fetch(feeLedger);
+    const treasuryBalance = await connection.getBalance(accounts.treasury);
+
+    assert(
+      treasuryBalance - initialTreasuryBalance == owedLd.toNumber(),
+      "treasury balance",
+    );
+    assert(ledger.withdrawnLd.eq(ledger.collectedLd), "nothing left owed");
+  });
+});
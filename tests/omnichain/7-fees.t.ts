@@ -0,0 +1,179 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { AnchorError, Program, Wallet } from "@coral-xyz/anchor";
+import { OtcMarket } from "../../target/types/otc_market";
+import { assert, expect } from "chai";
+import { Otc } from "./utils/otc";
+import { COMMITMENT } from "./config/constants";
+
+describe("Fees", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+  const program = anchor.workspace.OtcMarket as Program<OtcMarket>;
+  const connection = provider.connection;
+  const wallet = provider.wallet as Wallet;
+
+  const otc = new Otc(program, connection, wallet.payer);
+
+  const MAX_FEE_BPS = 1_000;
+  // overrides are keyed by token and eid, use ones no other test trades
+  const dstTokenAddress = Array.from(Keypair.generate().publicKey.toBytes());
+  const eid = 1;
+
+  let otcConfig: PublicKey;
+
+  before(async () => {
+    otcConfig = otc.deriver.config();
+  });
+
+  describe("Set Fee", () => {
+    it("should revert on Unauthorized", async () => {
+      const stranger = Keypair.generate();
+
+      try {
+        await program.methods
+          .setFee({ makerFeeBps: 0, takerFeeBps: 0 })
+          .accounts({ feeManager: stranger.publicKey, otcConfig })
+          .signers([stranger])
+          .rpc({ commitment: COMMITMENT });
+
+        assert(false, "should revert");
+      } catch (error: any) {
+        expect(error).to.be.instanceOf(AnchorError);
+        expect((error as AnchorError).error.errorCode.code).to.equal(
+          "Unauthorized",
+        );
+      }
+    });
+
+    it("should revert on ExcessiveFee", async () => {
+      try {
+        await program.methods
+          .setFee({ makerFeeBps: 0, takerFeeBps: MAX_FEE_BPS + 1 })
+          .accounts({ feeManager: wallet.publicKey, otcConfig })
+          .signers([wallet.payer])
+          .rpc({ commitment: COMMITMENT });
+
+        assert(false, "should revert");
+      } catch (error: any) {
+        expect(error).to.be.instanceOf(AnchorError);
+        expect((error as AnchorError).error.errorCode.code).to.equal(
+          "ExcessiveFee",
+        );
+      }
+    });
+
+    it("should set fee", async () => {
+      const initialConfig = await program.account.otcConfig.fetch(otcConfig);
+
+      await program.methods
+        .setFee({ makerFeeBps: 10, takerFeeBps: 20 })
+        .accounts({ feeManager: wallet.publicKey, otcConfig })
+        .signers([wallet.payer])
+        .rpc({ commitment: COMMITMENT });
+
+      const config = await program.account.otcConfig.fetch(otcConfig);
+      assert(config.makerFeeBps == 10, "maker fee");
+      assert(config.takerFeeBps == 20, "taker fee");
+
+      await program.methods
+        .setFee({
+          makerFeeBps: initialConfig.makerFeeBps,
+          takerFeeBps: initialConfig.takerFeeBps,
+        })
+        .accounts({ feeManager: wallet.publicKey, otcConfig })
+        .signers([wallet.payer])
+        .rpc({ commitment: COMMITMENT });
+    });
+  });
+
+  describe("Set Token Fee", () => {
+    it("should revert on ExcessiveFee", async () => {
+      try {
+        await program.methods
+          .setTokenFee({ dstTokenAddress, feeBps: MAX_FEE_BPS + 1 })
+          .accounts({
+            feeManager: wallet.publicKey,
+            tokenFee: otc.deriver.tokenFee(dstTokenAddress),
+            otcConfig,
+          })
+          .signers([wallet.payer])
+          .rpc({ commitment: COMMITMENT });
+
+        assert(false, "should revert");
+      } catch (error: any) {
+        expect(error).to.be.instanceOf(AnchorError);
+        expect((error as AnchorError).error.errorCode.code).to.equal(
+          "ExcessiveFee",
+        );
+      }
+    });
+
+    it("should set and remove token fee", async () => {
+      const tokenFee = otc.deriver.tokenFee(dstTokenAddress);
+
+      await program.methods
+        .setTokenFee({ dstTokenAddress, feeBps: 25 })
+        .accounts({ feeManager: wallet.publicKey, tokenFee, otcConfig })
+        .signers([wallet.payer])
+        .rpc({ commitment: COMMITMENT });
+
+      let feeOverride = await program.account.feeOverride.fetch(tokenFee);
+      assert(feeOverride.feeBps == 25, "token fee");
+
+      await program.methods
+        .setTokenFee({ dstTokenAddress, feeBps: null })
+        .accounts({ feeManager: wallet.publicKey, tokenFee, otcConfig })
+        .signers([wallet.payer])
+        .rpc({ commitment: COMMITMENT });
+
+      feeOverride = await program.account.feeOverride.fetch(tokenFee);
+      assert(feeOverride.feeBps === null, "token fee removed");
+    });
+  });
+
+  describe("Set Eid Fee", () => {
+    it("should revert on ExcessiveFee", async () => {
+      try {
+        await program.methods
+          .setEidFee({ eid, feeBps: MAX_FEE_BPS + 1 })
+          .accounts({
+            feeManager: wallet.publicKey,
+            eidFee: otc.deriver.eidFee(eid),
+            otcConfig,
+          })
+          .signers([wallet.payer])
+          .rpc({ commitment: COMMITMENT });
+
+        assert(false, "should revert");
+      } catch (error: any) {
+        expect(error).to.be.instanceOf(AnchorError);
+        expect((error as AnchorError).error.errorCode.code).to.equal(
+          "ExcessiveFee",
+        );
+      }
+    });
+
+    it("should set and remove eid fee", async () => {
+      const eidFee = otc.deriver.eidFee(eid);
+
+      await program.methods
+        .setEidFee({ eid, feeBps: 50 })
+        .accounts({ feeManager: wallet.publicKey, eidFee, otcConfig })
+        .signers([wallet.payer])
+        .rpc({ commitment: COMMITMENT });
+
+      let feeOverride = await program.account.feeOverride.fetch(eidFee);
+      assert(feeOverride.feeBps == 50, "eid fee");
+
+      await program.methods
+        .setEidFee({ eid, feeBps: null })
+        .accounts({ feeManager: wallet.publicKey, eidFee, otcConfig })
+        .signers([wallet.payer])
+        .rpc({ commitment: COMMITMENT });
+
+      feeOverride = await program.account.feeOverride.fetch(eidFee);
+      assert(feeOverride.feeBps === null, "eid fee removed");
+    });
+  });
+});
@@ -0,0 +1,159 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { AnchorError, Program, Wallet } from "@coral-xyz/anchor";
+import { OtcMarket } from "../../target/types/otc_market";
+import { assert, expect } from "chai";
+import { Otc } from "./utils/otc";
+import { OtcTools } from "./utils/otc-tools";
+import { AccountTools } from "./utils/account-tools";
+import { AmountsLD, COMMITMENT, GAS } from "./config/constants";
+
+describe("Referrer", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+  const program = anchor.workspace.OtcMarket as Program<OtcMarket>;
+  const connection = provider.connection;
+  const wallet = provider.wallet as Wallet;
+
+  const otc = new Otc(program, connection, wallet.payer);
+
+  const BPS_DENOMINATOR = 10_000;
+  const MAX_SHARE_BPS = 5_000;
+
+  let accounts: {
+    otcConfig: PublicKey;
+    referrer: Keypair;
+    seller: Keypair;
+    buyer: Keypair;
+  };
+
+  before(async () => {
+    accounts = {
+      otcConfig: otc.deriver.config(),
+      referrer: Keypair.generate(),
+      seller: Keypair.generate(),
+      buyer: Keypair.generate(),
+    };
+
+    await AccountTools.topUpAccounts(otc, accounts.seller);
+    await AccountTools.transferSol(
+      connection,
+      wallet.payer,
+      accounts.buyer.publicKey,
+      GAS,
+    );
+    // a share smaller than the rent exemption can not open the referrer
+    await AccountTools.transferSol(
+      connection,
+      wallet.payer,
+      accounts.referrer.publicKey,
+      GAS,
+    );
+  });
+
+  after(async () => {
+    await AccountTools.getRemainings(
+      connection,
+      [accounts.seller, accounts.buyer, accounts.referrer],
+      wallet.publicKey,
+    );
+  });
+
+  describe("Set Referrer", () => {
+    it("should revert on ExcessiveReferrerShare", async () => {
+      try {
+        await program.methods
+          .setReferrer({
+            referrer: accounts.referrer.publicKey,
+            maxShareBps: BPS_DENOMINATOR + 1,
+          })
+          .accounts({
+            feeManager: wallet.publicKey,
+            referrerConfig: otc.deriver.referrer(accounts.referrer.publicKey),
+            otcConfig: accounts.otcConfig,
+          })
+          .signers([wallet.payer])
+          .rpc({ commitment: COMMITMENT });
+
+        assert(false, "should revert");
+      } catch (error: any) {
+        expect(error).to.be.instanceOf(AnchorError);
+        expect((error as AnchorError).error.errorCode.code).to.equal(
+          "ExcessiveReferrerShare",
+        );
+      }
+    });
+
+    it("should set referrer", async () => {
+      const referrerConfig = otc.deriver.referrer(accounts.referrer.publicKey);
+
+      await program.methods
+        .setReferrer({
+          referrer: accounts.referrer.publicKey,
+          maxShareBps: MAX_SHARE_BPS,
+        })
+        .accounts({
+          feeManager: wallet.publicKey,
+          referrerConfig,
+          otcConfig: accounts.otcConfig,
+        })
+        .signers([wallet.payer])
+        .rpc({ commitment: COMMITMENT });
+
+      const referrer = await program.account.referrer.fetch(referrerConfig);
+      assert(referrer.maxShareBps == MAX_SHARE_BPS, "max share");
+    });
+  });
+
+  describe("Referred Accept", () => {
+    let offer: [PublicKey, number[]];
+
+    before(async () => {
+      offer = await OtcTools.createOffer(otc, accounts.seller);
+    });
+
+    it("should revert on ExcessiveReferrerShare", async () => {
+      try {
+        await OtcTools.acceptOffer(otc, offer, accounts.buyer, {
+          referrer: accounts.referrer.publicKey,
+          referrerShareBps: MAX_SHARE_BPS + 1,
+        });
+
+        assert(false, "should revert");
+      } catch (error: any) {
+        expect(error).to.be.instanceOf(AnchorError);
+        expect((error as AnchorError).error.errorCode.code).to.equal(
+          "ExcessiveReferrerShare",
+        );
+      }
+    });
+
+    it("should share the taker fee with the referrer", async () => {
+      const config = await program.account.otcConfig.fetch(accounts.otcConfig);
+      const initialReferrerBalance = await connection.getBalance(
+        accounts.referrer.publicKey,
+      );
+
+      await OtcTools.acceptOffer(otc, offer, accounts.buyer, {
+        referrer: accounts.referrer.publicKey,
+        referrerShareBps: MAX_SHARE_BPS,
+      });
+
+      // one to one sol offer - the dst amount is the src one
+      const takerFeeLd = Math.ceil(
+        (AmountsLD.SOL * config.takerFeeBps) / BPS_DENOMINATOR,
+      );
+      const referrerFeeLd = Math.floor(
+        (takerFeeLd * MAX_SHARE_BPS) / BPS_DENOMINATOR,
+      );
+
+      const referrerBalance = await connection.getBalance(
+        accounts.referrer.publicKey,
+      );
+      assert(
+        referrerBalance - initialReferrerBalance == referrerFeeLd,
+        "referrer balance",
+      );
+    });
+  });
+});
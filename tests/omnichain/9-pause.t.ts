@@ -0,0 +1,171 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { AnchorError, Program, Wallet } from "@coral-xyz/anchor";
+import { OtcMarket } from "../../target/types/otc_market";
+import { assert, expect } from "chai";
+import { Otc } from "./utils/otc";
+import { OtcTools } from "./utils/otc-tools";
+import { AccountTools } from "./utils/account-tools";
+import { COMMITMENT, GAS, SOLANA_EID } from "./config/constants";
+
+describe("Pause", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+  const program = anchor.workspace.OtcMarket as Program<OtcMarket>;
+  const connection = provider.connection;
+  const wallet = provider.wallet as Wallet;
+
+  const otc = new Otc(program, connection, wallet.payer);
+
+  let accounts: {
+    otcConfig: PublicKey;
+    seller: Keypair;
+    buyer: Keypair;
+    offer: [PublicKey, number[]];
+  };
+
+  const setPause = (paused: boolean) =>
+    program.methods
+      .setPause({ paused, pauseCancellations: false })
+      .accounts({ pauser: wallet.publicKey, otcConfig: accounts.otcConfig })
+      .signers([wallet.payer])
+      .rpc({ commitment: COMMITMENT });
+
+  const setEidPause = (paused: boolean) =>
+    program.methods
+      .setEidPause({ eid: SOLANA_EID, paused })
+      .accounts({
+        pauser: wallet.publicKey,
+        eidPause: otc.deriver.pause(SOLANA_EID),
+        otcConfig: accounts.otcConfig,
+      })
+      .signers([wallet.payer])
+      .rpc({ commitment: COMMITMENT });
+
+  // a partial accept of the monochain offer, it stays open for the next one
+  const acceptOffer = () =>
+    OtcTools.acceptOffer(otc, accounts.offer, accounts.buyer, {
+      srcAmountSd: new anchor.BN(1),
+    });
+
+  before(async () => {
+    const seller = Keypair.generate();
+    const buyer = Keypair.generate();
+    await AccountTools.topUpAccounts(otc, seller);
+    await AccountTools.transferSol(
+      connection,
+      wallet.payer,
+      buyer.publicKey,
+      GAS,
+    );
+
+    accounts = {
+      otcConfig: otc.deriver.config(),
+      seller,
+      buyer,
+      offer: await OtcTools.createOffer(otc, seller),
+    };
+  });
+
+  after(async () => {
+    // never leave the market paused for the rest of the suite
+    await setPause(false);
+    await setEidPause(false);
+
+    await AccountTools.getRemainings(
+      connection,
+      [accounts.seller, accounts.buyer],
+      wallet.publicKey,
+    );
+  });
+
+  describe("Set Pause", () => {
+    it("should revert on Unauthorized", async () => {
+      const stranger = Keypair.generate();
+
+      try {
+        await program.methods
+          .setPause({ paused: true, pauseCancellations: true })
+          .accounts({
+            pauser: stranger.publicKey,
+            otcConfig: accounts.otcConfig,
+          })
+          .signers([stranger])
+          .rpc({ commitment: COMMITMENT });
+
+        assert(false, "should revert");
+      } catch (error: any) {
+        expect(error).to.be.instanceOf(AnchorError);
+        expect((error as AnchorError).error.errorCode.code).to.equal(
+          "Unauthorized",
+        );
+      }
+    });
+
+    it("should block accepts while paused", async () => {
+      await setPause(true);
+
+      const config = await program.account.otcConfig.fetch(accounts.otcConfig);
+      assert(config.paused, "paused");
+      assert(!config.pauseCancellations, "cancellations enabled");
+
+      try {
+        await acceptOffer();
+
+        assert(false, "should revert");
+      } catch (error: any) {
+        expect(error).to.be.instanceOf(AnchorError);
+        expect((error as AnchorError).error.errorCode.code).to.equal("Paused");
+      }
+
+      await setPause(false);
+      await acceptOffer();
+    });
+  });
+
+  describe("Set Eid Pause", () => {
+    it("should revert on Unauthorized", async () => {
+      const stranger = Keypair.generate();
+
+      try {
+        await program.methods
+          .setEidPause({ eid: SOLANA_EID, paused: true })
+          .accounts({
+            pauser: stranger.publicKey,
+            eidPause: otc.deriver.pause(SOLANA_EID),
+            otcConfig: accounts.otcConfig,
+          })
+          .signers([stranger])
+          .rpc({ commitment: COMMITMENT });
+
+        assert(false, "should revert");
+      } catch (error: any) {
+        expect(error).to.be.instanceOf(AnchorError);
+        expect((error as AnchorError).error.errorCode.code).to.equal(
+          "Unauthorized",
+        );
+      }
+    });
+
+    it("should block accepts of the paused eid", async () => {
+      await setEidPause(true);
+
+      const eidPause = await program.account.eidPause.fetch(
+        otc.deriver.pause(SOLANA_EID),
+      );
+      assert(eidPause.paused, "eid paused");
+
+      try {
+        await acceptOffer();
+
+        assert(false, "should revert");
+      } catch (error: any) {
+        expect(error).to.be.instanceOf(AnchorError);
+        expect((error as AnchorError).error.errorCode.code).to.equal("Paused");
+      }
+
+      await setEidPause(false);
+      await acceptOffer();
+    });
+  });
+});
@@ -22,6 +22,10 @@ const createOfferReceiptBeet = new beet.BeetArgsStruct<CreateOfferReceipt>(
   [
     ["offerId", beet.uniformFixedSizeArray(beet.u8, 32)],
     ["srcAmountLd", beet.u64 as beet.FixedSizeBeet<anchor.BN>],
+    ["makerFeeLd", beet.u64 as beet.FixedSizeBeet<anchor.BN>],
+    ["makerFeeBps", beet.u16],
+    ["escrowedAmountLd", beet.u64 as beet.FixedSizeBeet<anchor.BN>],
+    ["transferFeeLd", beet.u64 as beet.FixedSizeBeet<anchor.BN>],
   ],
   "CreateOfferReceipt",
 );
@@ -35,7 +39,11 @@ type AcceptOfferReceipt = anchor.IdlTypes<OtcMarket>["AcceptOfferReceipt"];
 const acceptOfferReceiptBeet = new beet.BeetArgsStruct<AcceptOfferReceipt>(
   [
     ["dstAmountLd", beet.u64 as beet.FixedSizeBeet<anchor.BN>],
-    ["feeLd", beet.u64 as beet.FixedSizeBeet<anchor.BN>],
+    ["takerFeeLd", beet.u64 as beet.FixedSizeBeet<anchor.BN>],
+    ["takerFeeBps", beet.u16],
+    ["referrerFeeLd", beet.u64 as beet.FixedSizeBeet<anchor.BN>],
+    ["dstSellerAmountLd", beet.u64 as beet.FixedSizeBeet<anchor.BN>],
+    ["transferFeeLd", beet.u64 as beet.FixedSizeBeet<anchor.BN>],
   ],
   "AcceptOfferReceipt",
 );
@@ -43,3 +51,20 @@ const acceptOfferReceiptBeet = new beet.BeetArgsStruct<AcceptOfferReceipt>(
 export const quoteAcceptOfferBeet: beet.FixedSizeBeet<
   [AcceptOfferReceipt, MessagingFee]
 > = beet.fixedSizeTuple([acceptOfferReceiptBeet, messagingFeeBeet]);
+
+type TopUpOfferReceipt = anchor.IdlTypes<OtcMarket>["TopUpOfferReceipt"];
+
+const topUpOfferReceiptBeet = new beet.BeetArgsStruct<TopUpOfferReceipt>(
+  [
+    ["srcAmountLd", beet.u64 as beet.FixedSizeBeet<anchor.BN>],
+    ["makerFeeLd", beet.u64 as beet.FixedSizeBeet<anchor.BN>],
+    ["makerFeeBps", beet.u16],
+    ["escrowedAmountLd", beet.u64 as beet.FixedSizeBeet<anchor.BN>],
+    ["transferFeeLd", beet.u64 as beet.FixedSizeBeet<anchor.BN>],
+  ],
+  "TopUpOfferReceipt",
+);
+
+export const quoteTopUpOfferBeet: beet.FixedSizeBeet<
+  [TopUpOfferReceipt, MessagingFee]
+> = beet.fixedSizeTuple([topUpOfferReceiptBeet, messagingFeeBeet]);
@@ -15,9 +15,84 @@ export class OtcPdaDeriver {
     )[0];
   }
 
-  escrow(): PublicKey {
+  escrow(offerId: number[]): PublicKey {
     return PublicKey.findProgramAddressSync(
-      [Buffer.from("Escrow", "utf8")],
+      [Buffer.from("Escrow", "utf8"), Buffer.from(offerId)],
+      this.programId,
+    )[0];
+  }
+
+  sellerNonce(seller: PublicKey): PublicKey {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("SellerNonce", "utf8"), seller.toBytes()],
+      this.programId,
+    )[0];
+  }
+
+  escrowLedger(tokenAddress: number[]): PublicKey {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("EscrowLedger", "utf8"), Buffer.from(tokenAddress)],
+      this.programId,
+    )[0];
+  }
+
+  treasury(): PublicKey {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("Treasury", "utf8")],
+      this.programId,
+    )[0];
+  }
+
+  feeLedger(tokenAddress: number[]): PublicKey {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("FeeLedger", "utf8"), Buffer.from(tokenAddress)],
+      this.programId,
+    )[0];
+  }
+
+  pause(eid: number): PublicKey {
+    return PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("Pause", "utf8"),
+        this.config().toBytes(),
+        new anchor.BN(eid).toArrayLike(Buffer, "be", 4),
+      ],
+      this.programId,
+    )[0];
+  }
+
+  tokenFee(dstTokenAddress: number[]): PublicKey {
+    return PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("TokenFee", "utf8"),
+        this.config().toBytes(),
+        Buffer.from(dstTokenAddress),
+      ],
+      this.programId,
+    )[0];
+  }
+
+  eidFee(srcEid: number): PublicKey {
+    return PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("EidFee", "utf8"),
+        this.config().toBytes(),
+        new anchor.BN(srcEid).toArrayLike(Buffer, "be", 4),
+      ],
+      this.programId,
+    )[0];
+  }
+
+  referrer(referrer: PublicKey): PublicKey {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("Referrer", "utf8"), referrer.toBytes()],
+      this.programId,
+    )[0];
+  }
+
+  buyerFill(offerId: number[], buyer: PublicKey): PublicKey {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("BuyerFill", "utf8"), Buffer.from(offerId), buyer.toBytes()],
       this.programId,
     )[0];
   }
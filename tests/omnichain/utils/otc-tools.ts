@@ -2,10 +2,15 @@ import * as anchor from "@coral-xyz/anchor";
 import { Keypair, PublicKey } from "@solana/web3.js";
 import { Program } from "@coral-xyz/anchor";
 import { OtcMarket } from "../../../target/types/otc_market";
-import { AmountsLD, ExchangeRates } from "../config/constants";
+import { AmountsLD, COMMITMENT, ExchangeRates } from "../config/constants";
 import { EndpointId } from "@layerzerolabs/lz-definitions";
 import { Otc } from "./otc";
 import { solanaToArbSepConfig } from "../config/peer";
+import {
+  AcceptOfferParams,
+  getAcceptOfferAccounts,
+  getAcceptOfferParams,
+} from "../../helpers/accept_offer";
 
 export class OtcTools {
   static async createOffer(
@@ -14,6 +19,7 @@ export class OtcTools {
     dstSeller?: number[], // if defined - crosschain
     srcTokenMint: PublicKey | null = null, // if defined - src token is spl
     dstTokenAddress: number[] = Array.from(PublicKey.default.toBytes()), // if defined - dst token is not native
+    overrides: Partial<anchor.IdlTypes<OtcMarket>["CreateOfferParams"]> = {}, // e.g. expiry or fill constraints
   ): Promise<[PublicKey, number[]]> {
     const [dstEid, dstSellerAddress] = !!dstSeller // is crosschain ?
       ? [solanaToArbSepConfig.to.eid, dstSeller]
@@ -28,6 +34,13 @@ export class OtcTools {
       dstTokenAddress,
       srcAmountLd: new anchor.BN(!srcTokenMint ? AmountsLD.SOL : AmountsLD.SPL),
       exchangeRateSd: new anchor.BN(ExchangeRates.OneToOne),
+      expiresAt: null,
+      nonce: await OtcTools.getNextNonce(otc, srcSeller.publicKey),
+      minFillSd: new anchor.BN(0),
+      allOrNothing: false,
+      allowedBuyer: null,
+      buyersRoot: null,
+      ...overrides,
     };
 
     const fee = (await otc.quoteCreateOffer(params, srcSeller))[1];
@@ -35,6 +48,103 @@ export class OtcTools {
     return await otc.createOffer(params, fee, srcSeller, srcTokenMint);
   }
 
+  // monochain accept, the whole remaining amount unless overridden
+  static async acceptOffer(
+    otc: Otc,
+    offer: [PublicKey, number[]],
+    buyer: Keypair,
+    overrides: Partial<AcceptOfferParams> = {},
+  ): Promise<string> {
+    const offerAccount = await otc.program.account.offer.fetch(offer[0]);
+    const params: AcceptOfferParams = {
+      ...getAcceptOfferParams(
+        offer[1],
+        offerAccount.srcAmountSd,
+        Array.from(buyer.publicKey.toBytes()),
+      ),
+      ...overrides,
+    };
+
+    return await otc.program.methods
+      .acceptOffer(params, {
+        nativeFee: new anchor.BN(0),
+        lzTokenFee: new anchor.BN(0),
+      })
+      .accounts(
+        await getAcceptOfferAccounts(
+          otc.program,
+          { otcConfig: otc.deriver.config(), dstBuyer: buyer },
+          offer[0],
+          params,
+        ),
+      )
+      .signers([buyer])
+      .rpc({ commitment: COMMITMENT });
+  }
+
+  // monochain sol offer sent without a quote, a revert surfaces as AnchorError
+  static async createNativeOffer(
+    otc: Otc,
+    seller: Keypair,
+    overrides: Partial<anchor.IdlTypes<OtcMarket>["CreateOfferParams"]> = {},
+  ): Promise<string> {
+    const sellerAddress = Array.from(seller.publicKey.toBytes());
+    const nativeToken = Array.from(PublicKey.default.toBytes());
+    const eid = EndpointId.SOLANA_V2_TESTNET;
+
+    const params: anchor.IdlTypes<OtcMarket>["CreateOfferParams"] = {
+      dstSellerAddress: sellerAddress,
+      dstEid: eid,
+      dstTokenAddress: nativeToken,
+      srcAmountLd: new anchor.BN(AmountsLD.SOL),
+      exchangeRateSd: new anchor.BN(ExchangeRates.OneToOne),
+      expiresAt: null,
+      nonce: await OtcTools.getNextNonce(otc, seller.publicKey),
+      minFillSd: new anchor.BN(0),
+      allOrNothing: false,
+      allowedBuyer: null,
+      buyersRoot: null,
+      ...overrides,
+    };
+    const offer = await OtcTools.getOfferFromParams(
+      otc.program,
+      sellerAddress,
+      eid,
+      eid,
+      nativeToken,
+      nativeToken,
+      params.exchangeRateSd,
+      params.nonce,
+    );
+
+    return await otc.program.methods
+      .createOffer(params, {
+        nativeFee: new anchor.BN(0),
+        lzTokenFee: new anchor.BN(0),
+      })
+      .accounts({
+        seller: seller.publicKey,
+        offer: offer[0],
+        sellerNonce: otc.deriver.sellerNonce(seller.publicKey),
+        feeLedger: otc.deriver.feeLedger(nativeToken),
+        escrowLedger: otc.deriver.escrowLedger(nativeToken),
+        otcConfig: otc.deriver.config(),
+        eidPause: otc.deriver.pause(eid),
+        escrow: otc.deriver.escrow(offer[1]),
+        srcTokenMint: null,
+        srcSellerAta: null,
+        srcEscrowAta: null,
+        treasury: otc.deriver.treasury(),
+        srcTreasuryAta: null,
+        peer: null,
+        enforcedOptions: null,
+        tokenProgram: null,
+        associatedTokenProgram: null,
+      })
+      .signers([seller])
+      .rpc({ commitment: COMMITMENT });
+  }
+
   static async getOfferFromParams(
     program: Program<OtcMarket>,
     srcSellerAddress: number[],
@@ -43,6 +153,7 @@ export class OtcTools {
     srcTokenAddress: number[],
     dstTokenAddress: number[],
     exchangeRateSd: anchor.BN,
+    nonce: anchor.BN,
   ): Promise<[PublicKey, number[]]> {
     const offerId: Uint8Array = await program.methods
       .hashOffer(
@@ -52,6 +163,7 @@ export class OtcTools {
         srcTokenAddress,
         dstTokenAddress,
         exchangeRateSd,
+        nonce,
       )
       .view();
 
@@ -61,6 +173,14 @@ export class OtcTools {
     ];
   }
 
+  static async getNextNonce(otc: Otc, seller: PublicKey): Promise<anchor.BN> {
+    const account = await otc.program.account.sellerNonce.fetchNullable(
+      otc.deriver.sellerNonce(seller),
+    );
+
+    return account ? account.nonce.addn(1) : new anchor.BN(1);
+  }
+
   static async getOfferFromId(
     program: Program<OtcMarket>,
     offerId: string,
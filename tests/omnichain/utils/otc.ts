@@ -7,7 +7,12 @@ import {
   PublicKey,
   ComputeBudgetProgram,
 } from "@solana/web3.js";
-import { getOrCreateAssociatedTokenAccount } from "@solana/spl-token";
+import {
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  getAssociatedTokenAddressSync,
+  getOrCreateAssociatedTokenAccount,
+  TOKEN_PROGRAM_ID,
+} from "@solana/spl-token";
 import { OtcPdaDeriver } from "./otc-pda-deriver";
 import { OtcTools } from "./otc-tools";
 import { EndpointId } from "@layerzerolabs/lz-definitions";
@@ -21,7 +26,6 @@ import {
   ENDPOINT_PROGRAM_ID,
   GAS,
   PEER,
-  TREASURY_SECRET_KEY,
 } from "../config/constants";
 import { hexlify } from "ethers/lib/utils";
 import {
@@ -29,10 +33,12 @@ import {
   messagingFeeBeet,
   quoteAcceptOfferBeet,
   quoteCreateOfferBeet,
+  quoteTopUpOfferBeet,
 } from "./beet-decoder";
 import { assert } from "chai";
 import { isNativeToken } from "./is-native-token";
 import { V0TransactionTools } from "./v0-transaction-tools";
+import { getTokenProgram } from "../../helpers/spl";
 import { transferSol } from "../../helpers/helper";
 import { addressToBytes32 } from "@layerzerolabs/lz-v2-utilities";
 
@@ -59,6 +65,37 @@ export class Otc {
     );
   }
 
+  async acceptAdmin(pendingAdmin: Keypair): Promise<string> {
+    const otcConfig = this.deriver.config();
+    const endpointProgram = new PublicKey(ENDPOINT_PROGRAM_ID);
+
+    // endpoint set_delegate accounts, the new admin becomes the oapp delegate
+    const [oappRegistry] = PublicKey.findProgramAddressSync(
+      [Buffer.from("OApp", "utf8"), otcConfig.toBytes()],
+      endpointProgram,
+    );
+    const [eventAuthority] = PublicKey.findProgramAddressSync(
+      [Buffer.from("__event_authority", "utf8")],
+      endpointProgram,
+    );
+
+    return await this.program.methods
+      .acceptAdmin()
+      .accounts({
+        pendingAdmin: pendingAdmin.publicKey,
+        otcConfig,
+      })
+      .remainingAccounts([
+        { pubkey: endpointProgram, isSigner: false, isWritable: false },
+        { pubkey: otcConfig, isSigner: false, isWritable: false },
+        { pubkey: oappRegistry, isSigner: false, isWritable: true },
+        { pubkey: eventAuthority, isSigner: false, isWritable: false },
+        { pubkey: endpointProgram, isSigner: false, isWritable: false },
+      ])
+      .signers([pendingAdmin])
+      .rpc({ commitment: COMMITMENT });
+  }
+
   async quoteCreateOffer(
     params: anchor.IdlTypes<OtcMarket>["CreateOfferParams"],
     seller: Keypair,
@@ -102,6 +139,9 @@ export class Otc {
         srcTokenMint,
         peer,
         enforcedOptions,
+        tokenProgram: srcTokenMint
+          ? await getTokenProgram(this.connection, srcTokenMint)
+          : null,
       })
       .remainingAccounts(remainingAccounts)
       .instruction();
@@ -127,8 +167,7 @@ export class Otc {
     const crosschain = params.dstEid !== srcEid;
 
     const otcConfig = this.deriver.config();
-    const escrow = this.deriver.escrow();
-    const offerPromise = OtcTools.getOfferFromParams(
+    const offer = await OtcTools.getOfferFromParams(
       this.program,
       Array.from(seller.publicKey.toBytes()),
       srcEid,
@@ -138,31 +177,46 @@ export class Otc {
         : Array.from(PublicKey.default.toBytes()),
       params.dstTokenAddress,
       params.exchangeRateSd,
+      params.nonce,
     );
-
-    const [srcEscrowAtaPromise, srcSellerAtaPromise] = srcTokenMint
+    const srcTokenAddress = srcTokenMint
+      ? Array.from(srcTokenMint.toBytes())
+      : Array.from(PublicKey.default.toBytes());
+    const escrow = this.deriver.escrow(offer[1]);
+    const treasury = this.deriver.treasury();
+    const tokenProgram = srcTokenMint
+      ? await getTokenProgram(this.connection, srcTokenMint)
+      : null;
+
+    // the escrow vault of the offer and its ata are created by the program
+    const [srcEscrowAta, srcSellerAta, srcTreasuryAta] = srcTokenMint
       ? [
-          getOrCreateAssociatedTokenAccount(
-            this.connection,
-            seller,
+          getAssociatedTokenAddressSync(
             srcTokenMint,
             escrow,
             true,
-          ).then((account) => account.address),
-          getOrCreateAssociatedTokenAccount(
-            this.connection,
-            seller,
+            tokenProgram,
+          ),
+          (
+            await getOrCreateAssociatedTokenAccount(
+              this.connection,
+              seller,
+              srcTokenMint,
+              seller.publicKey,
+              false,
+              undefined,
+              undefined,
+              tokenProgram,
+            )
+          ).address,
+          getAssociatedTokenAddressSync(
             srcTokenMint,
-            seller.publicKey,
-          ).then((account) => account.address),
+            treasury,
+            true,
+            tokenProgram,
+          ),
         ]
-      : [Promise.resolve(null), Promise.resolve(null)];
-
-    const [offer, srcEscrowAta, srcSellerAta] = await Promise.all([
-      offerPromise,
-      srcEscrowAtaPromise,
-      srcSellerAtaPromise,
-    ]);
+      : [null, null, null];
 
     const [peer, enforcedOptions, remainingAccounts] = crosschain
       ? [
@@ -195,13 +249,23 @@ export class Otc {
       .accounts({
         seller: seller.publicKey,
         offer: offer[0],
+        sellerNonce: this.deriver.sellerNonce(seller.publicKey),
+        feeLedger: this.deriver.feeLedger(srcTokenAddress),
+        escrowLedger: this.deriver.escrowLedger(srcTokenAddress),
         otcConfig,
+        eidPause: this.deriver.pause(params.dstEid),
         escrow,
         srcTokenMint, // required for src spl token
         srcSellerAta, // required for src spl token
         srcEscrowAta, // required for src spl token
+        treasury,
+        srcTreasuryAta, // required for src spl token with a maker fee
         peer, // required for cross chain offer
         enforcedOptions, // required for cross chain offer
+        tokenProgram, // spl token or token-2022 program of the src mint
+        associatedTokenProgram: srcTokenMint
+          ? ASSOCIATED_TOKEN_PROGRAM_ID
+          : null,
       })
       .remainingAccounts(remainingAccounts)
       .instruction();
@@ -286,7 +350,12 @@ export class Otc {
       .accounts({
         otcConfig: otcConfig,
         offer: offerAddress,
+        tokenFee: this.deriver.tokenFee(dstToken),
+        eidFee: this.deriver.eidFee(offerAccount.srcEid),
+        referrerConfig: null,
+        buyerFill: null,
         dstTokenMint,
+        tokenProgram: dstTokenMint ? TOKEN_PROGRAM_ID : null,
         peer: peer,
         enforcedOptions: enforcedOptions,
       })
@@ -329,7 +398,8 @@ export class Otc {
     const crosschain = offerAccount.srcEid !== offerAccount.dstEid;
 
     const otcConfig = this.deriver.config();
-    const treasury = Keypair.fromSecretKey(TREASURY_SECRET_KEY).publicKey;
+    // fees accrue in the program-owned treasury vault
+    const treasury = this.deriver.treasury();
 
     const [peer, enforcedOptions, remainingAccounts] = crosschain
       ? [
@@ -360,30 +430,51 @@ export class Otc {
     const dstSeller = new PublicKey(offerAccount.dstSellerAddress);
 
     await transferSol(this.connection, this.payer, dstSeller, GAS);
-    await transferSol(this.connection, this.payer, treasury, GAS);
 
     const acceptIx = await this.program.methods
       .acceptOffer(params, fee)
-      // TODO: fix accounts
       .accounts({
         buyer: buyer.publicKey,
         otcConfig,
         offer: offerAddress,
-        // src token
+        // the offer is never fully filled here, its rent stays put
+        offerPayer: null,
+        eidPause: this.deriver.pause(offerAccount.srcEid),
+        feeLedger: this.deriver.feeLedger(dstToken),
+        tokenFee: this.deriver.tokenFee(dstToken),
+        eidFee: this.deriver.eidFee(offerAccount.srcEid),
+        buyerFill: null,
+        // src token - escrowed on the src chain of a crosschain offer
         srcBuyerAta: null,
+        srcSellerAta: null,
         srcEscrowAta: null,
-        escrow: this.deriver.escrow(),
+        escrow: null,
+        escrowLedger: null,
         srcTokenMint: null,
         // dst token
-        dstBuyerAta: null,
+        dstBuyerAta: dstTokenMint
+          ? getAssociatedTokenAddressSync(dstTokenMint, buyer.publicKey)
+          : null,
         dstSeller,
-        dstSellerAta: null,
-        dstTreasuryAta: null,
+        dstSellerAta: dstTokenMint
+          ? getAssociatedTokenAddressSync(dstTokenMint, dstSeller)
+          : null,
+        dstTreasuryAta: dstTokenMint
+          ? getAssociatedTokenAddressSync(dstTokenMint, treasury, true)
+          : null,
         treasury,
-        dstTokenMint: null,
+        referrerConfig: null,
+        referrer: null,
+        dstReferrerAta: null,
+        dstTokenMint,
         // crosschain
         peer,
         enforcedOptions,
+        associatedTokenProgram: dstTokenMint
+          ? ASSOCIATED_TOKEN_PROGRAM_ID
+          : null,
+        tokenProgram: dstTokenMint ? TOKEN_PROGRAM_ID : null,
+        srcTokenProgram: null,
       })
       .remainingAccounts(remainingAccounts)
       .instruction();
@@ -451,6 +542,142 @@ export class Otc {
     return signature;
   }
 
+  async quoteTopUpOffer(
+    params: anchor.IdlTypes<OtcMarket>["TopUpOfferParams"],
+    seller: Keypair,
+  ): Promise<[anchor.IdlTypes<OtcMarket>["TopUpOfferReceipt"], MessagingFee]> {
+    const offerAddress = PublicKey.findProgramAddressSync(
+      [Buffer.from(params.offerId)],
+      this.program.programId,
+    )[0];
+    const offerAccount = await this.program.account.offer.fetch(offerAddress);
+
+    const srcEid = offerAccount.srcEid;
+    const dstEid = offerAccount.dstEid;
+    const crosschain = srcEid !== dstEid;
+
+    const srcTokenMint = isNativeToken(offerAccount.srcTokenAddress)
+      ? null
+      : new PublicKey(offerAccount.srcTokenAddress);
+
+    const otcConfig = this.deriver.config();
+
+    const [peer, enforcedOptions, remainingAccounts] = crosschain
+      ? [
+          this.deriver.peer(dstEid),
+          this.deriver.enforcedOptions(dstEid),
+          await this.endpoint.getQuoteIXAccountMetaForCPI(
+            this.connection,
+            seller.publicKey,
+            {
+              dstEid,
+              srcEid,
+              sender: hexlify(otcConfig.toBytes()),
+              receiver: PEER,
+            },
+            new UlnProgram.Uln(
+              (
+                await this.endpoint.getSendLibrary(
+                  this.connection,
+                  otcConfig,
+                  dstEid,
+                )
+              ).programId,
+            ),
+          ),
+        ]
+      : [null, null, []];
+
+    const ix = await this.program.methods
+      .quoteTopUpOffer(params, false)
+      .accounts({
+        otcConfig,
+        offer: offerAddress,
+        srcTokenMint,
+        peer,
+        enforcedOptions,
+        tokenProgram: srcTokenMint ? TOKEN_PROGRAM_ID : null,
+      })
+      .remainingAccounts(remainingAccounts)
+      .instruction();
+
+    const response = await simulateTransaction(
+      this.connection,
+      [ix],
+      this.program.programId,
+      seller.publicKey,
+      COMMITMENT,
+    );
+
+    return quoteTopUpOfferBeet.read(response, 0);
+  }
+
+  async quoteReduceOffer(
+    params: anchor.IdlTypes<OtcMarket>["ReduceOfferParams"],
+    extraOptions: Buffer,
+  ): Promise<MessagingFee> {
+    const offerAddress = PublicKey.findProgramAddressSync(
+      [Buffer.from(params.offerId)],
+      this.program.programId,
+    )[0];
+    const offerAccount = await this.program.account.offer.fetch(offerAddress);
+
+    const srcEid = offerAccount.srcEid;
+    const dstEid = offerAccount.dstEid;
+    const seller = new PublicKey(offerAccount.srcSellerAddress);
+    const otcConfig = this.deriver.config();
+
+    const [peer, enforcedOptions, remainingAccounts] = [
+      this.deriver.peer(dstEid),
+      this.deriver.enforcedOptions(dstEid),
+      await this.endpoint.getQuoteIXAccountMetaForCPI(
+        this.connection,
+        seller,
+        {
+          dstEid,
+          srcEid,
+          sender: hexlify(otcConfig.toBytes()),
+          receiver: PEER,
+        },
+        new UlnProgram.Uln(
+          (
+            await this.endpoint.getSendLibrary(
+              this.connection,
+              otcConfig,
+              dstEid,
+            )
+          ).programId,
+        ),
+      ),
+    ];
+
+    const ix = await this.program.methods
+      .quoteReduceOffer(
+        offerAccount.srcSellerAddress,
+        params,
+        extraOptions,
+        false,
+      )
+      .accounts({
+        otcConfig,
+        offer: offerAddress,
+        peer,
+        enforcedOptions,
+      })
+      .remainingAccounts(remainingAccounts)
+      .instruction();
+
+    const response = await simulateTransaction(
+      this.connection,
+      [ix],
+      this.program.programId,
+      seller,
+      COMMITMENT,
+    );
+
+    return messagingFeeBeet.read(response, 0);
+  }
+
   async quoteCancelOfferOrder(
     offerId: number[],
     extraOptions: Buffer,
@@ -560,7 +787,10 @@ export class Otc {
         seller: seller.publicKey,
         otcConfig,
         offer: offerAddress,
-        escrow: this.deriver.escrow(),
+        eidPause: this.deriver.pause(dstEid),
+        // the escrow is released once the dst chain confirms the cancel
+        escrow: null,
+        escrowLedger: null,
         // src token spl
         srcSellerAta: null,
         srcEscrowAta: null,